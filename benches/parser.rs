@@ -0,0 +1,273 @@
+//! Benchmarks of the parser on inputs of several megabytes.
+//!
+//! Run with `cargo bench --features parser`.
+//!
+//! Each input is also parsed by [`baseline::TokenStream`], a copy of the linear-scan tokenizer of version 1.0.1, to
+//! compare the current parser against it.
+
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use ansi_control_codes::parser::{ByteTokenStream, TokenStream};
+
+/// The size of each generated input in bytes.
+const INPUT_SIZE: usize = 8 * 1024 * 1024;
+
+/// The number of times each benchmark is repeated. The fastest repetition is reported.
+const REPETITIONS: usize = 10;
+
+/// Repeats `input` until the result is at least [`INPUT_SIZE`] bytes long.
+fn repeat(input: &str) -> String {
+    input.repeat(INPUT_SIZE / input.len() + 1)
+}
+
+/// Runs `parse` on `input` [`REPETITIONS`] times, and prints the throughput of the fastest run.
+fn bench(name: &str, input: &str, parse: impl Fn(&str) -> usize) {
+    let mut fastest = Duration::MAX;
+    let mut tokens = 0;
+    for _ in 0..REPETITIONS {
+        let start = Instant::now();
+        tokens = black_box(parse(black_box(input)));
+        fastest = fastest.min(start.elapsed());
+    }
+
+    let megabytes = input.len() as f64 / (1024.0 * 1024.0);
+    println!(
+        "{:<44} {:>8.2} MiB {:>10} tokens {:>10.2?} {:>10.2} MiB/s",
+        name,
+        megabytes,
+        tokens,
+        fastest,
+        megabytes / fastest.as_secs_f64()
+    );
+}
+
+fn main() {
+    let inputs = [
+        (
+            "plain text",
+            repeat("The quick brown fox jumps over the lazy dog.\n"),
+        ),
+        (
+            "non-ascii text",
+            repeat("Zwölf Boxkämpfer jagen Viktor quer über den großen Sylter Deich.\n"),
+        ),
+        (
+            "colored log",
+            repeat(
+                "\x1b[2m2024-01-01 12:00:00\x1b[0m \x1b[1;32mINFO\x1b[0m request \x1b[36m/index.html\x1b[0m took 3ms\n",
+            ),
+        ),
+        (
+            "dense control sequences",
+            repeat("\x1b[1;1H\x1b[2K\x1b[38;5;208mx\x1b[0m\x1b[?25l\r\x07"),
+        ),
+        (
+            "control strings",
+            repeat("\x1b]0;window title\x07\x1b]8;;https://example.com\x1b\\link\x1b]8;;\x1b\\\n"),
+        ),
+    ];
+
+    for (name, input) in &inputs {
+        bench(&format!("TokenStream: {}", name), input, |input| {
+            TokenStream::from(input).count()
+        });
+        bench(&format!("ByteTokenStream: {}", name), input, |input| {
+            ByteTokenStream::from(input.as_bytes()).count()
+        });
+        bench(&format!("1.0.1 TokenStream: {}", name), input, |input| {
+            baseline::TokenStream::from(input).count()
+        });
+    }
+}
+
+/// The linear-scan tokenizer of version 1.0.1.
+///
+/// Every character is compared against the tables of control functions one by one, and the parameters of control
+/// sequences are collected into `String`s, as in version 1.0.1. Control strings are not recognized.
+mod baseline {
+    use ansi_control_codes::{c0::*, c1::*, independent_control_functions::*, ControlFunction};
+
+    const C0_CODES: [ControlFunction; 31] = [
+        ACK, BEL, BS, CAN, CR, DC1, DC2, DC3, DC4, DLE, EM, ENQ, EOT, ETB, ETX, FF, HT, IS1, IS2,
+        IS3, IS4, LF, NAK, NUL, SI, SO, SOH, STX, SUB, SYN, VT,
+    ];
+
+    const C1_CODES: [ControlFunction; 27] = [
+        BPH, NBH, NEL, SSA, ESA, HTS, HTJ, VTS, PLD, PLU, RI, SS2, SS3, DCS, PU1, PU2, STS, CCH,
+        MW, SPA, EPA, SOS, SCI, ST, OSC, PM, APC,
+    ];
+
+    const INDEPENDENT_CODES: [ControlFunction; 10] =
+        [DMI, INT, EMI, RIS, CMD, LS2, LS3, LS3R, LS2R, LS1R];
+
+    /// A token of the linear-scan tokenizer. Control sequences keep their parameters as `String`s.
+    #[allow(dead_code)]
+    pub(crate) enum Token<'a> {
+        String(&'a str),
+        ControlFunction(ControlFunction<'a>),
+        ControlSequence(&'a str, Vec<String>),
+    }
+
+    pub(crate) struct TokenStream<'a> {
+        value: &'a str,
+        position: usize,
+        max_position: usize,
+    }
+
+    impl<'a> TokenStream<'a> {
+        pub(crate) fn from(value: &'a str) -> Self {
+            TokenStream {
+                value,
+                position: 0,
+                max_position: value.len(),
+            }
+        }
+
+        fn get_next_char_boundary(&self, position: usize) -> usize {
+            if position >= self.max_position {
+                return position;
+            }
+
+            let mut next_boundary = position + 1;
+            while !self.value.is_char_boundary(next_boundary) {
+                next_boundary += 1
+            }
+            next_boundary
+        }
+
+        fn emit_current_string(&mut self, position: usize) -> Option<Token<'a>> {
+            let mut emit_token = None;
+            if position != self.position {
+                emit_token = Some(Token::String(&self.value[self.position..position]));
+
+                self.position = position;
+            }
+
+            emit_token
+        }
+
+        /// Emits the pending string, or else `token`, which ends before `next_position`.
+        fn emit(
+            &mut self,
+            position: usize,
+            next_position: usize,
+            token: Token<'a>,
+        ) -> Option<Token<'a>> {
+            self.emit_current_string(position).or_else(|| {
+                self.position = next_position;
+                Some(token)
+            })
+        }
+    }
+
+    impl<'a> Iterator for TokenStream<'a> {
+        type Item = Token<'a>;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            let mut current_position = self.position;
+            while current_position < self.max_position {
+                let next_char_boundary = self.get_next_char_boundary(current_position);
+
+                let current_char = &self.value[current_position..next_char_boundary];
+                if !current_char.is_ascii() {
+                    current_position = next_char_boundary;
+                    continue;
+                }
+
+                if let Some(code) = C0_CODES.iter().find(|code| **code == current_char) {
+                    let token = Token::ControlFunction(code.clone());
+                    return self.emit(current_position, next_char_boundary, token);
+                }
+
+                if ESC == current_char {
+                    if self.max_position == next_char_boundary {
+                        return self.emit(
+                            current_position,
+                            next_char_boundary,
+                            Token::ControlFunction(ESC),
+                        );
+                    }
+
+                    let next_next_char_boundary = self.get_next_char_boundary(next_char_boundary);
+
+                    let current_char = &self.value[next_char_boundary..next_next_char_boundary];
+                    let control_sequence = &self.value[current_position..next_next_char_boundary];
+                    if !current_char.is_ascii() {
+                        return self.emit(
+                            current_position,
+                            next_char_boundary,
+                            Token::ControlFunction(ESC),
+                        );
+                    }
+
+                    if let Some(code) = C1_CODES
+                        .iter()
+                        .chain(INDEPENDENT_CODES.iter())
+                        .find(|code| **code == control_sequence)
+                    {
+                        let token = Token::ControlFunction(code.clone());
+                        return self.emit(current_position, next_next_char_boundary, token);
+                    }
+
+                    if control_sequence != CSI {
+                        return self.emit(
+                            current_position,
+                            next_char_boundary,
+                            Token::ControlFunction(ESC),
+                        );
+                    }
+
+                    let control_sequence_position = next_next_char_boundary;
+                    let mut intermediate_byte = false;
+                    let mut current_position_cs = control_sequence_position;
+                    let mut next_position_cs =
+                        self.get_next_char_boundary(control_sequence_position);
+                    loop {
+                        let current_char = &self.value[current_position_cs..next_position_cs];
+                        if current_char.len() != 1 {
+                            break;
+                        }
+
+                        let byte = current_char.as_bytes()[0];
+                        if (0x40..=0x7e).contains(&byte) {
+                            let (value, parameters) = if intermediate_byte {
+                                (
+                                    &self.value[current_position_cs - 1..next_position_cs],
+                                    &self.value[control_sequence_position..current_position_cs - 1],
+                                )
+                            } else {
+                                (
+                                    current_char,
+                                    &self.value[control_sequence_position..current_position_cs],
+                                )
+                            };
+                            let parameters = parameters.split(';').map(String::from).collect();
+                            let token = Token::ControlSequence(value, parameters);
+                            return self.emit(current_position, next_position_cs, token);
+                        } else if intermediate_byte {
+                            break;
+                        } else if !(0x30..=0x3f).contains(&byte) {
+                            intermediate_byte = byte == 0x20;
+                            if !intermediate_byte {
+                                break;
+                            }
+                        }
+
+                        if next_position_cs == self.max_position {
+                            break;
+                        }
+                        current_position_cs = next_position_cs;
+                        next_position_cs = self.get_next_char_boundary(current_position_cs);
+                    }
+                }
+
+                current_position = next_char_boundary;
+            }
+
+            self.emit_current_string(current_position)
+        }
+    }
+}
@@ -17,6 +17,34 @@
 //! print!("{}", CUP(Some(5), Some(13)));
 //! ```
 //!
+//! ## Compile-Time Constants
+//!
+//! Control sequences with literal parameters can be created as `&'static str` at compile time with the macros
+//! [`csi!`][crate::csi] and [`sgr!`][crate::sgr]. They need no formatting at runtime.
+//!
+//! ```
+//! use ansi_control_codes::{csi, sgr};
+//!
+//! const CLEAR_SCREEN: &str = csi!(ED, 2);
+//! const WARNING: &str = sgr!(HighIntensity, RedForeground);
+//!
+//! assert_eq!(CLEAR_SCREEN, "\x1b[2J");
+//! assert_eq!(WARNING, "\x1b[1;31m");
+//! ```
+//!
+//! ## Decoding Control Sequences
+//!
+//! A control sequence, for example one that was found by the [parser][crate::parser], can be decoded into a
+//! [`Sequence`]. The parameters of the control sequence are then available as numeric values, or as the enums of the
+//! selective parameter values.
+//!
+//! ```
+//! use ansi_control_codes::control_sequences::{Sequence, CUP};
+//!
+//! let cursor_position = Sequence::try_from(&CUP(Some(5), Some(13)));
+//! assert_eq!(cursor_position, Ok(Sequence::CursorPosition { line: 5, column: 13 }));
+//! ```
+//!
 //! ## Overview of the Control Sequences
 //!
 //! ### Without Intermediate Bytes
@@ -68,91 +96,291 @@
 //! instead follow the ECMA standard. This is intended.
 #![allow(non_snake_case)]
 
-use crate::{modes::Mode, ControlFunction};
+use std::{error::Error, fmt};
+
+use crate::{
+    modes::{Mode, PrivateMode},
+    ControlFunction, ControlFunctionType, Parameter, Parameters, PrivateParameter,
+};
 
 macro_rules! sequence {
-    // numeric control sequence with no intermediate byte and no default value
-    ($xx:literal / $yy:literal, numeric $param:ident) => {
-        ControlFunction::new_sequence(ascii!($xx / $yy), vec![$param.to_string()])
+    // numeric control sequence with one parameter and no default value
+    ($function:ident, numeric $param:ident) => {
+        ControlFunction::new_sequence(function::$function, [Parameter::from($param)])
     };
-    // numeric control sequence with no intermediate byte and default value
-    ($xx:literal / $yy:literal, numeric $param:ident, default $default:literal) => {
+    // numeric control sequence with one parameter and default value
+    ($function:ident, numeric $param:ident, default $default:literal) => {
         ControlFunction::new_sequence(
-            ascii!($xx / $yy),
-            vec![$param.unwrap_or($default).to_string()],
+            function::$function,
+            [Parameter::from($param.unwrap_or($default))],
         )
     };
-    // numeric control sequence with no intermediate byte, two parameters and default values
-    ($xx:literal / $yy:literal, numeric $param1:ident, default $default1:literal, numeric $param2:ident, default $default2:literal) => {
+    // numeric control sequence with two parameters and no default values
+    ($function:ident, numeric $param1:ident, numeric $param2:ident) => {
         ControlFunction::new_sequence(
-            ascii!($xx / $yy),
-            vec![
-                $param1.unwrap_or($default1).to_string(),
-                $param2.unwrap_or($default2).to_string(),
-            ],
+            function::$function,
+            [Parameter::from($param1), Parameter::from($param2)],
         )
     };
-    // selective control sequence with no intermediate byte and default value
-    ($xx:literal / $yy:literal, selective default $param:ident) => {
+    // numeric control sequence with two parameters and default values
+    ($function:ident, numeric $param1:ident, default $default1:literal, numeric $param2:ident, default $default2:literal) => {
         ControlFunction::new_sequence(
-            ascii!($xx / $yy),
-            vec![($param.unwrap_or_default() as u32).to_string()],
+            function::$function,
+            [
+                Parameter::from($param1.unwrap_or($default1)),
+                Parameter::from($param2.unwrap_or($default2)),
+            ],
         )
     };
-    // selective control sequence with intermediate byte and default value
-    ($xx1:literal / $yy1:literal, $xx2:literal / $yy2:literal, selective default $param:ident) => {
+    // selective control sequence with one parameter and default value
+    ($function:ident, selective default $param:ident) => {
         ControlFunction::new_sequence(
-            ascii!($xx1 / $yy1, $xx2 / $yy2),
-            vec![($param.unwrap_or_default() as u32).to_string()],
+            function::$function,
+            [Parameter::from($param.unwrap_or_default() as u32)],
         )
     };
-    // selective control sequence with intermediate byte and two default value
-    ($xx1:literal / $yy1:literal, $xx2:literal / $yy2:literal, selective default $param1:ident, selective default $param2:ident) => {
+    // selective control sequence with two parameters and default values
+    ($function:ident, selective default $param1:ident, selective default $param2:ident) => {
         ControlFunction::new_sequence(
-            ascii!($xx1 / $yy1, $xx2 / $yy2),
-            vec![
-                ($param1.unwrap_or_default() as u32).to_string(),
-                ($param2.unwrap_or_default() as u32).to_string(),
+            function::$function,
+            [
+                Parameter::from($param1.unwrap_or_default() as u32),
+                Parameter::from($param2.unwrap_or_default() as u32),
             ],
         )
     };
-    // numeric control sequence with intermediate byte, one parameters, and no default value
-    ($xx1:literal / $yy1:literal, $xx2:literal / $yy2:literal, numeric $param:ident) => {
-        ControlFunction::new_sequence(ascii!($xx1 / $yy1, $xx2 / $yy2), vec![$param.to_string()])
-    };
-    // numeric control sequence with intermediate byte, one parameters, and default value
-    ($xx1:literal / $yy1:literal, $xx2:literal / $yy2:literal, numeric $param:ident, default $default:literal) => {
+    // control sequence with variadic number of selective arguments
+    ($function:ident, variadic selective $vector:expr) => {
         ControlFunction::new_sequence(
-            ascii!($xx1 / $yy1, $xx2 / $yy2),
-            vec![$param.unwrap_or($default).to_string()],
+            function::$function,
+            $vector
+                .iter()
+                .map(|e| Parameter::from(*e as u32))
+                .collect::<Parameters>(),
         )
     };
-    // numeric control sequence with intermediate byte, two parameters, and no default value
-    ($xx1:literal / $yy1:literal, $xx2:literal / $yy2:literal, numeric $param1:ident, numeric $param2:ident) => {
-        ControlFunction::new_sequence(
-            ascii!($xx1 / $yy1, $xx2 / $yy2),
-            vec![$param1.to_string(), $param2.to_string()],
+}
+
+/// Creates a control sequence as a `&'static str` at compile time.
+///
+/// The first argument is the name of one of the control sequences of the module
+/// [`control_sequences`][crate::control_sequences], followed by its numeric parameters as literals. The parameters are
+/// used as they are given, no default values are filled in. Omitted parameters are interpreted by the receiving device
+/// with their default value.
+///
+/// The result can be used in constants and statics, so that common control sequences need no formatting at runtime.
+/// Use `.as_bytes()` to obtain a `&'static [u8]`.
+///
+/// ```
+/// use ansi_control_codes::control_sequences::{ErasePage, CUP, ED};
+/// use ansi_control_codes::csi;
+///
+/// const CLEAR_SCREEN: &str = csi!(ED, 2);
+/// const HOME: &[u8] = csi!(CUP).as_bytes();
+///
+/// assert_eq!(CLEAR_SCREEN, ED(Some(ErasePage::BeginToEnd)).to_string());
+/// assert_eq!(HOME, b"\x1b[H");
+/// assert_eq!(csi!(CUP, 5, 13), CUP(Some(5), Some(13)).to_string());
+/// ```
+///
+/// Parameters must be unsigned integers.
+///
+/// ```compile_fail
+/// use ansi_control_codes::csi;
+///
+/// const INVALID: &str = csi!(CUP, "5");
+/// ```
+#[macro_export]
+macro_rules! csi {
+    ($function:ident $(, $parameter:literal)* $(,)?) => {{
+        // parameters must be unsigned integers.
+        $(const _: u32 = $parameter;)*
+        concat!(
+            "\x1b[",
+            $crate::__csi_parameters!($($parameter),*),
+            $crate::__csi_function!($function)
         )
-    };
-    // numeric control sequence with intermediate byte, two parameters, and default values
-    ($xx1:literal / $yy1:literal, $xx2:literal / $yy2:literal, numeric $param1:ident, default $default1:literal, numeric $param2:ident, default $default2:literal) => {
-        ControlFunction::new_sequence(
-            ascii!($xx1 / $yy1, $xx2 / $yy2),
-            vec![
-                $param1.unwrap_or($default1).to_string(),
-                $param2.unwrap_or($default2).to_string(),
-            ],
+    }};
+}
+
+/// Creates a SELECT GRAPHIC RENDITION control sequence as a `&'static str` at compile time.
+///
+/// The arguments are the names of variants of [`GraphicRendition`][crate::control_sequences::GraphicRendition]. Like
+/// [`csi!`], the result can be used in constants and statics. Colors of the extended color palettes are not supported.
+///
+/// ```
+/// use ansi_control_codes::control_sequences::{GraphicRendition, SGR};
+/// use ansi_control_codes::sgr;
+///
+/// const WARNING: &str = sgr!(HighIntensity, RedForeground);
+/// const RESET: &str = sgr!(Default);
+///
+/// assert_eq!(
+///     WARNING,
+///     SGR(Some(vec![GraphicRendition::HighIntensity, GraphicRendition::RedForeground])).to_string()
+/// );
+/// assert_eq!(RESET, "\x1b[0m");
+/// ```
+#[macro_export]
+macro_rules! sgr {
+    ($($graphic_rendition:ident),* $(,)?) => {
+        concat!(
+            "\x1b[",
+            $crate::__sgr_parameters!($($graphic_rendition),*),
+            "m"
         )
     };
-    // control sequence with variadic number of selective arguments
-    ($xx:literal / $yy: literal, variadic selective $vector:expr) => {
-        ControlFunction::new_sequence(
-            ascii!($xx / $yy),
-            $vector.iter().map(|e| (*e as u32).to_string()).collect(),
+}
+
+/// Joins the parameters of [`csi!`] with `03/11`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __csi_parameters {
+    () => {
+        ""
+    };
+    ($parameter:literal) => {
+        concat!($parameter)
+    };
+    ($parameter:literal, $($parameters:literal),+) => {
+        concat!($parameter, ";", $crate::__csi_parameters!($($parameters),+))
+    };
+}
+
+/// Joins the parameters of [`sgr!`] with `03/11`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __sgr_parameters {
+    () => {
+        ""
+    };
+    ($graphic_rendition:ident) => {
+        $crate::__graphic_rendition!($graphic_rendition)
+    };
+    ($graphic_rendition:ident, $($graphic_renditions:ident),+) => {
+        concat!(
+            $crate::__graphic_rendition!($graphic_rendition),
+            ";",
+            $crate::__sgr_parameters!($($graphic_renditions),+)
         )
     };
 }
 
+/// Defines the intermediate bytes and the final byte of each control sequence. They are used by the functions of this
+/// module, and by [`csi!`] through the exported macro `__csi_function`.
+macro_rules! functions {
+    ($($function:ident = $value:literal),* $(,)?) => {
+        /// The intermediate bytes and the final byte of each control sequence.
+        mod function {
+            $(pub(super) const $function: &str = $value;)*
+        }
+
+        /// The intermediate bytes and the final byte of each control sequence, used by [`csi!`].
+        #[doc(hidden)]
+        #[macro_export]
+        macro_rules! __csi_function {
+            $(($function) => { $value };)*
+        }
+
+        /// The name and the intermediate bytes and final byte of each control sequence.
+        #[cfg(test)]
+        pub(crate) const FUNCTIONS: &[(&str, &str)] = &[$((stringify!($function), $value)),*];
+    };
+}
+
+functions! {
+    CBT = "Z",
+    CHA = "G",
+    CHT = "I",
+    CNL = "E",
+    CPL = "F",
+    CPR = "R",
+    CTC = "W",
+    CUB = "D",
+    CUD = "B",
+    CUF = "C",
+    CUP = "H",
+    CUU = "A",
+    CVT = "Y",
+    DA = "c",
+    DAQ = "o",
+    DCH = "P",
+    DL = "M",
+    DSR = "n",
+    DTA = " T",
+    EA = "O",
+    ECH = "X",
+    ED = "J",
+    EF = "N",
+    EL = "K",
+    FNK = " W",
+    FNT = " D",
+    GCC = " _",
+    GSM = " B",
+    GSS = " C",
+    HPA = "`",
+    HPB = "j",
+    HPR = "a",
+    HVP = "f",
+    ICH = "@",
+    IDCS = " O",
+    IGS = " M",
+    IL = "L",
+    JFY = " F",
+    MC = "i",
+    NP = "U",
+    PEC = " Z",
+    PFS = " J",
+    PP = "V",
+    PPA = " P",
+    PPB = " R",
+    PPR = " Q",
+    PTX = "\\",
+    QUAD = " H",
+    REP = "b",
+    RM = "l",
+    SACS = " \\",
+    SAPV = " ]",
+    SCO = " e",
+    SCP = " k",
+    SCS = " g",
+    SD = "T",
+    SDS = "]",
+    SEE = "Q",
+    SEF = " Y",
+    SGR = "m",
+    SHS = " K",
+    SIMD = "^",
+    SL = " @",
+    SLH = " U",
+    SLL = " V",
+    SLS = " h",
+    SM = "h",
+    SPD = " S",
+    SPH = " i",
+    SPI = " G",
+    SPL = " j",
+    SPQR = " X",
+    SR = " A",
+    SRCS = " f",
+    SRS = "[",
+    SSU = " I",
+    SSW = " [",
+    STAB = " ^",
+    SU = "S",
+    SVS = " L",
+    TAC = " b",
+    TALE = " a",
+    TATE = " `",
+    TBC = "g",
+    TCC = " c",
+    TSR = " d",
+    TSS = " E",
+    VPA = "d",
+    VPB = "k",
+    VPR = "e",
+}
+
 /// Cursor Backward Tabulation.
 ///
 /// `CBT` causes the active presentation position to be moved to the character position corresponding to the `n`-th
@@ -160,7 +388,7 @@ macro_rules! sequence {
 ///
 /// Default value for `n` is `1`.
 pub fn CBT(n: Option<u32>) -> ControlFunction<'static> {
-    sequence!(05 / 10, numeric n, default 1)
+    sequence!(CBT, numeric n, default 1)
 }
 
 /// Cursor Character Absolute.
@@ -170,7 +398,7 @@ pub fn CBT(n: Option<u32>) -> ControlFunction<'static> {
 ///
 /// Default value for `n` is `1`.
 pub fn CHA(n: Option<u32>) -> ControlFunction<'static> {
-    sequence!(04 / 07, numeric n, default 1)
+    sequence!(CHA, numeric n, default 1)
 }
 
 /// Cursor Forward Tabulation.
@@ -180,7 +408,7 @@ pub fn CHA(n: Option<u32>) -> ControlFunction<'static> {
 ///
 /// Default value for `n` is `1`.
 pub fn CHT(n: Option<u32>) -> ControlFunction<'static> {
-    sequence!(04 / 09, numeric n, default 1)
+    sequence!(CHT, numeric n, default 1)
 }
 
 /// Cursor Next Line.
@@ -190,7 +418,7 @@ pub fn CHT(n: Option<u32>) -> ControlFunction<'static> {
 ///
 /// Default value for `n` is `1`.
 pub fn CNL(n: Option<u32>) -> ControlFunction<'static> {
-    sequence!(04 / 05, numeric n, default 1)
+    sequence!(CNL, numeric n, default 1)
 }
 
 /// Cursor Preceding Line.
@@ -200,7 +428,7 @@ pub fn CNL(n: Option<u32>) -> ControlFunction<'static> {
 ///
 /// Default value for `n` is `1`.
 pub fn CPL(n: Option<u32>) -> ControlFunction<'static> {
-    sequence!(04 / 06, numeric n, default 1)
+    sequence!(CPL, numeric n, default 1)
 }
 
 /// Active Position Report.
@@ -217,7 +445,7 @@ pub fn CPL(n: Option<u32>) -> ControlFunction<'static> {
 ///
 /// Default value for `n` and `m` is `1`.
 pub fn CPR(n: Option<u32>, m: Option<u32>) -> ControlFunction<'static> {
-    sequence!(05 / 02, numeric n, default 1, numeric m, default 1)
+    sequence!(CPR, numeric n, default 1, numeric m, default 1)
 }
 
 /// Valid parameter values to the function [`CTC`].
@@ -246,6 +474,16 @@ pub enum TabulationControl {
     ClearAllLineTabulationStops,
 }
 
+selection!(TabulationControl {
+    SetCharacterTabulationStop,
+    SetLineTabulationStop,
+    ClearCharacterTabulationStop,
+    ClearLineTabulationStop,
+    ClearCharacterTabulationStopsInLine,
+    ClearAllCharacterTabulationStops,
+    ClearAllLineTabulationStops,
+});
+
 /// Cursor Tabulation Control.
 ///
 /// `CTC` causes one or more tabulation stops to be set or cleared in the presentation component, depending on the
@@ -253,7 +491,7 @@ pub enum TabulationControl {
 ///
 /// Default value for `s` is [`TabulationControl::SetCharacterTabulationStop`].
 pub fn CTC(s: Option<TabulationControl>) -> ControlFunction<'static> {
-    sequence!(05 / 07, selective default s)
+    sequence!(CTC, selective default s)
 }
 
 /// Cursor Left.
@@ -263,7 +501,7 @@ pub fn CTC(s: Option<TabulationControl>) -> ControlFunction<'static> {
 ///
 /// Default value for `n` is `1`.
 pub fn CUB(n: Option<u32>) -> ControlFunction<'static> {
-    sequence!(04 / 04, numeric n, default 1)
+    sequence!(CUB, numeric n, default 1)
 }
 
 /// Cursor Down.
@@ -273,7 +511,7 @@ pub fn CUB(n: Option<u32>) -> ControlFunction<'static> {
 ///
 /// Default value for `n` is `1`.
 pub fn CUD(n: Option<u32>) -> ControlFunction<'static> {
-    sequence!(04 / 02, numeric n, default 1)
+    sequence!(CUD, numeric n, default 1)
 }
 
 /// Cursor Right.
@@ -283,7 +521,7 @@ pub fn CUD(n: Option<u32>) -> ControlFunction<'static> {
 ///
 /// Default value for `n` is `1`.
 pub fn CUF(n: Option<u32>) -> ControlFunction<'static> {
-    sequence!(04 / 03, numeric n, default 1)
+    sequence!(CUF, numeric n, default 1)
 }
 
 /// Cursor Position.
@@ -293,7 +531,7 @@ pub fn CUF(n: Option<u32>) -> ControlFunction<'static> {
 ///
 /// Default value for `n` and `m` is `1`.
 pub fn CUP(n: Option<u32>, m: Option<u32>) -> ControlFunction<'static> {
-    sequence!(04 / 08, numeric n, default 1, numeric m, default 1)
+    sequence!(CUP, numeric n, default 1, numeric m, default 1)
 }
 
 /// Cursor Up.
@@ -303,7 +541,7 @@ pub fn CUP(n: Option<u32>, m: Option<u32>) -> ControlFunction<'static> {
 ///
 /// Default value for `n` is `1`.
 pub fn CUU(n: Option<u32>) -> ControlFunction<'static> {
-    sequence!(04 / 01, numeric n, default 1)
+    sequence!(CUU, numeric n, default 1)
 }
 
 /// Cursor Line Tabulation.
@@ -313,7 +551,7 @@ pub fn CUU(n: Option<u32>) -> ControlFunction<'static> {
 ///
 /// Default value for `n` is `1`.
 pub fn CVT(n: Option<u32>) -> ControlFunction<'static> {
-    sequence!(05 / 09, numeric n, default 1)
+    sequence!(CVT, numeric n, default 1)
 }
 
 /// Valid parameter values to the function [`DA`].
@@ -328,6 +566,15 @@ pub enum DeviceAttributes {
     Identify(u32),
 }
 
+impl From<u32> for DeviceAttributes {
+    fn from(value: u32) -> Self {
+        match value {
+            0 => DeviceAttributes::Request,
+            value => DeviceAttributes::Identify(value),
+        }
+    }
+}
+
 /// Device Attributes.
 ///
 /// With a parameter [`DeviceAttributes::Identify`] (not equal to 0), `DA` is used to identify the device which sends
@@ -343,7 +590,7 @@ pub fn DA(s: Option<DeviceAttributes>) -> ControlFunction<'static> {
         Some(DeviceAttributes::Identify(x)) => x,
         None => 0,
     };
-    sequence!(06 / 03, numeric v)
+    sequence!(DA, numeric v)
 }
 
 /// Valid parameter values to the function [`DAQ`].
@@ -389,6 +636,21 @@ pub enum AreaQualification {
     Reversed,
 }
 
+selection!(AreaQualification {
+    UnprotectedUnguarded,
+    ProtectedGuarded,
+    GraphicCharacterInput,
+    NumericInput,
+    AlphabeticInput,
+    InputAlignedRight,
+    FillZeros,
+    SetCharacterTabulationStop,
+    ProtectedUnguarded,
+    FillSpaces,
+    InputAlignedLeft,
+    Reversed,
+});
+
 /// Define Area Qualification.
 ///
 /// `DAQ` is used to indicate that the active presentation position in the presentation component is the first character
@@ -406,7 +668,7 @@ pub enum AreaQualification {
 /// The control functions for area definitions ([`DAQ`], [`EPA`][crate::c1::EPA], [`ESA`][crate::c1::ESA],
 /// [`SPA`][crate::c1::SPA], [`SSA`][crate::c1::SSA]) should not be used within an [`SRS`] string or an [`SDS`] string.
 pub fn DAQ(s: Option<AreaQualification>) -> ControlFunction<'static> {
-    sequence!(06 / 15, selective default s)
+    sequence!(DAQ, selective default s)
 }
 
 /// Delete Character.
@@ -431,7 +693,7 @@ pub fn DAQ(s: Option<AreaQualification>) -> ControlFunction<'static> {
 ///
 /// Default value for `n` is `1`.
 pub fn DCH(n: Option<u32>) -> ControlFunction<'static> {
-    sequence!(05 / 00, numeric n, default 1)
+    sequence!(DCH, numeric n, default 1)
 }
 
 /// Delete Line.
@@ -462,7 +724,7 @@ pub fn DCH(n: Option<u32>) -> ControlFunction<'static> {
 ///
 /// The default value for `n` is `1`.
 pub fn DL(n: Option<u32>) -> ControlFunction<'static> {
-    sequence!(04 / 13, numeric n, default 1)
+    sequence!(DL, numeric n, default 1)
 }
 
 /// Valid parameter values to the function [`DSR`].
@@ -492,6 +754,16 @@ pub enum DeviceStatusReport {
     RequestActivePositionReport,
 }
 
+selection!(DeviceStatusReport {
+    Ready,
+    BusyRepeat,
+    BusyLater,
+    MalfunctionRepeat,
+    MalfunctionLater,
+    RequestDeviceStatusReport,
+    RequestActivePositionReport,
+});
+
 /// Device Status Report.
 ///
 /// `DSR` is used either to report the status of the sending device or to request a status report from the receiving
@@ -505,7 +777,7 @@ pub enum DeviceStatusReport {
 ///
 /// The default value for `s` is [`DeviceStatusReport::Ready`].
 pub fn DSR(s: Option<DeviceStatusReport>) -> ControlFunction<'static> {
-    sequence!(06 / 14, selective default s)
+    sequence!(DSR, selective default s)
 }
 
 /// Dimension Text Area.
@@ -520,7 +792,7 @@ pub fn DSR(s: Option<DeviceStatusReport>) -> ControlFunction<'static> {
 /// The unit in which the parameter value is expressed is that established by the parameter value of SELECT SIZE UNIT
 /// (`SSU`).
 pub fn DTA(n: u32, m: u32) -> ControlFunction<'static> {
-    sequence!(02 / 00, 05 / 04, numeric n, numeric m)
+    sequence!(DTA, numeric n, numeric m)
 }
 
 /// Valid parameter values to the function [`EA`].
@@ -537,6 +809,12 @@ pub enum EraseArea {
     BeginToEnd,
 }
 
+selection!(EraseArea {
+    ActivePositionToEnd,
+    BeginToActivePosition,
+    BeginToEnd,
+});
+
 /// Erase in Area.
 ///
 /// If the DEVICE COMPONENT SELECT MODE ([`DCSM`][crate::modes::DCSM]) is set to PRESENTATION, `EA` causes some or all
@@ -552,7 +830,7 @@ pub enum EraseArea {
 ///
 /// The default value of `s` is [`EraseArea::ActivePositionToEnd`].
 pub fn EA(s: Option<EraseArea>) -> ControlFunction<'static> {
-    sequence!(04 / 15, selective default s)
+    sequence!(EA, selective default s)
 }
 
 /// Erase Character.
@@ -569,7 +847,7 @@ pub fn EA(s: Option<EraseArea>) -> ControlFunction<'static> {
 ///
 /// The default value for `n` is `1`.
 pub fn ECH(n: Option<u32>) -> ControlFunction<'static> {
-    sequence!(05 / 08, numeric n, default 1)
+    sequence!(ECH, numeric n, default 1)
 }
 
 /// Valid parameter values to the function [`ED`].
@@ -586,6 +864,12 @@ pub enum ErasePage {
     BeginToEnd,
 }
 
+selection!(ErasePage {
+    ActivePositionToEnd,
+    BeginToActivePosition,
+    BeginToEnd,
+});
+
 /// Erase In Page.
 ///
 /// If the DEVICE COMPONENT SELECT MODE ([`DCSM`][crate::modes::DCSM]) is set to PRESENTATION, `ED` causes some or all
@@ -601,7 +885,7 @@ pub enum ErasePage {
 ///
 /// The default value of `s` is [`ErasePage::ActivePositionToEnd`].
 pub fn ED(s: Option<ErasePage>) -> ControlFunction<'static> {
-    sequence!(04 / 10, selective default s)
+    sequence!(ED, selective default s)
 }
 
 /// Valid parameter values to the function [`EF`].
@@ -618,6 +902,12 @@ pub enum EraseField {
     BeginToEnd,
 }
 
+selection!(EraseField {
+    ActivePositionToEnd,
+    BeginToActivePosition,
+    BeginToEnd,
+});
+
 /// Erase In Field.
 ///
 /// If the DEVICE COMPONENT SELECT MODE ([`DCSM`][crate::modes::DCSM]) is set to PRESENTATION, `EF` causes some or all
@@ -633,7 +923,7 @@ pub enum EraseField {
 ///
 /// The default value for `s` is [`EraseField::ActivePositionToEnd`].
 pub fn EF(s: Option<EraseField>) -> ControlFunction<'static> {
-    sequence!(04 / 14, selective default s)
+    sequence!(EF, selective default s)
 }
 
 /// Valid parameter values to the function [`EL`].
@@ -650,6 +940,12 @@ pub enum EraseLine {
     BeginToEnd,
 }
 
+selection!(EraseLine {
+    ActivePositionToEnd,
+    BeginToActivePosition,
+    BeginToEnd,
+});
+
 /// Erase In Line.
 ///
 /// If the DEVICE COMPONENT SELECT MODE ([`DCSM`][crate::modes::DCSM]) is set to PRESENTATION, `EL` causes some or all
@@ -665,14 +961,14 @@ pub enum EraseLine {
 ///
 /// The default value for `s` is [`EraseLine::ActivePositionToEnd`].
 pub fn EL(s: Option<EraseLine>) -> ControlFunction<'static> {
-    sequence!(04 / 11, selective default s)
+    sequence!(EL, selective default s)
 }
 
 /// Function Key.
 ///
 /// `FNK` is a control function in which the parameter value identifies the function key which has been operated.
 pub fn FNK(n: u32) -> ControlFunction<'static> {
-    sequence!(02 / 00, 05 / 07, numeric n)
+    sequence!(FNK, numeric n)
 }
 
 /// Valid parameter values to the function [`FNT`].
@@ -710,6 +1006,19 @@ pub enum Font {
     Alternative9,
 }
 
+selection!(Font {
+    Primary,
+    Alternative1,
+    Alternative2,
+    Alternative3,
+    Alternative4,
+    Alternative5,
+    Alternative6,
+    Alternative7,
+    Alternative8,
+    Alternative9,
+});
+
 /// Font Selection.
 ///
 /// `FNT` is used to identify the character font to be selected as primary or alternative font by subsequent occurrences
@@ -725,7 +1034,7 @@ pub fn FNT(s: Option<Font>, t: Option<u32>) -> ControlFunction<'static> {
         None => (Font::default()) as u32,
     };
     let b = t.unwrap_or(0);
-    sequence!(02 / 00, 04 / 04, numeric a, numeric b)
+    sequence!(FNT, numeric a, numeric b)
 }
 
 /// Valid parameter values to the function [`GCC`].
@@ -744,6 +1053,12 @@ pub enum GraphicCharacterCombination {
     EndOfCombination,
 }
 
+selection!(GraphicCharacterCombination {
+    CombineTwo,
+    StartOfCombination,
+    EndOfCombination,
+});
+
 /// Graphic Character Combination
 ///
 /// `GCC` is used to indicate that two or more graphic characters are to be imaged as one single graphic symbol.
@@ -756,7 +1071,7 @@ pub enum GraphicCharacterCombination {
 /// symbol. In the simplest case, two components may be "half-width" and side-by-side. For example in Japanese text a
 /// pair of characters may be presented side-by-side, and occupy the space of a normal-size Kanji character.
 pub fn GCC(s: Option<GraphicCharacterCombination>) -> ControlFunction<'static> {
-    sequence!(02 / 00, 05 / 15, selective default s)
+    sequence!(GCC, selective default s)
 }
 
 /// Graphic Size Modification.
@@ -770,7 +1085,7 @@ pub fn GCC(s: Option<GraphicCharacterCombination>) -> ControlFunction<'static> {
 ///
 /// The default value for `h`, and `w` is `100`.
 pub fn GSM(h: Option<u32>, w: Option<u32>) -> ControlFunction<'static> {
-    sequence!(02 / 00, 04 / 02, numeric h, default 100, numeric w, default 100)
+    sequence!(GSM, numeric h, default 100, numeric w, default 100)
 }
 
 /// Graphic Size Selection.
@@ -784,7 +1099,7 @@ pub fn GSM(h: Option<u32>, w: Option<u32>) -> ControlFunction<'static> {
 /// The unit in which the parameter value is expressed is that established by the parameter value of SELECT SIZE UNIT
 /// ([`SSU`]).
 pub fn GSS(n: u32) -> ControlFunction<'static> {
-    sequence!(02 / 00, 04/03, numeric n)
+    sequence!(GSS, numeric n)
 }
 
 /// Character Position Absolute.
@@ -794,7 +1109,7 @@ pub fn GSS(n: u32) -> ControlFunction<'static> {
 ///
 /// The default value for `n` is `1`.
 pub fn HPA(n: Option<u32>) -> ControlFunction<'static> {
-    sequence!(06 / 00, numeric n, default 1)
+    sequence!(HPA, numeric n, default 1)
 }
 
 /// Character Position Backward.
@@ -804,7 +1119,7 @@ pub fn HPA(n: Option<u32>) -> ControlFunction<'static> {
 ///
 /// The default value for `n` is `1`.
 pub fn HPB(n: Option<u32>) -> ControlFunction<'static> {
-    sequence!(06 / 10, numeric n, default 1)
+    sequence!(HPB, numeric n, default 1)
 }
 
 /// Character Position Forward.
@@ -814,7 +1129,7 @@ pub fn HPB(n: Option<u32>) -> ControlFunction<'static> {
 ///
 /// The default value for `n` is `1`.
 pub fn HPR(n: Option<u32>) -> ControlFunction<'static> {
-    sequence!(06 / 01, numeric n, default 1)
+    sequence!(HPR, numeric n, default 1)
 }
 
 /// Character And Line Position.
@@ -824,7 +1139,7 @@ pub fn HPR(n: Option<u32>) -> ControlFunction<'static> {
 ///
 /// The default value for `n` and `m` is `1`.
 pub fn HVP(n: Option<u32>, m: Option<u32>) -> ControlFunction<'static> {
-    sequence!(06 / 06, numeric n, default 1, numeric m, default 1)
+    sequence!(HVP, numeric n, default 1, numeric m, default 1)
 }
 
 /// Insert Character.
@@ -853,7 +1168,7 @@ pub fn HVP(n: Option<u32>, m: Option<u32>) -> ControlFunction<'static> {
 ///
 /// The default value for `n` is `1`.
 pub fn ICH(n: Option<u32>) -> ControlFunction<'static> {
-    sequence!(04 / 00, numeric n, default 1)
+    sequence!(ICH, numeric n, default 1)
 }
 
 /// Valid parameter values to the function [`IDCS`].
@@ -872,6 +1187,16 @@ pub enum IdentifyDeviceControlString {
     Private(u32),
 }
 
+impl From<u32> for IdentifyDeviceControlString {
+    fn from(value: u32) -> Self {
+        match value {
+            1 => IdentifyDeviceControlString::Diagnostic,
+            2 => IdentifyDeviceControlString::DynamicallyRedefinableCharacterSet,
+            value => IdentifyDeviceControlString::Private(value),
+        }
+    }
+}
+
 /// Identify Device Control String.
 ///
 /// `IDCS` is used to specify the purpose and format of the command string of subsequent DEVICE CONTROL STRINGS
@@ -888,7 +1213,7 @@ pub fn IDCS(s: IdentifyDeviceControlString) -> ControlFunction<'static> {
         IdentifyDeviceControlString::DynamicallyRedefinableCharacterSet => 2,
     };
 
-    sequence!(02 / 00, 04 / 15, numeric v)
+    sequence!(IDCS, numeric v)
 }
 
 /// Identify Graphic Subrepertoire.
@@ -898,7 +1223,7 @@ pub fn IDCS(s: IdentifyDeviceControlString) -> ControlFunction<'static> {
 ///
 /// The parameter value of `IGS` identifies a graphic character repertoire registered in accordance with ISO/IEC 7350.
 pub fn IGS(n: u32) -> ControlFunction<'static> {
-    sequence!(02 / 00, 04 / 13, numeric n)
+    sequence!(IGS, numeric n)
 }
 
 /// Insert Line.
@@ -929,7 +1254,7 @@ pub fn IGS(n: u32) -> ControlFunction<'static> {
 ///
 /// The default value for `n` is `1`.
 pub fn IL(n: Option<u32>) -> ControlFunction<'static> {
-    sequence!(04 / 12, numeric n, default 1)
+    sequence!(IL, numeric n, default 1)
 }
 
 /// Valid parameter values to the function [`JFY`].
@@ -964,6 +1289,18 @@ pub enum Justification {
     ItalianHyphenation,
 }
 
+selection!(Justification {
+    None,
+    WordFill,
+    WordSpace,
+    LetterSpace,
+    Hyphenation,
+    Left,
+    Centre,
+    Right,
+    ItalianHyphenation,
+});
+
 /// Justify.
 ///
 /// `JFY` is used to indicate the beginning of a string of graphic characters in the presentation component that are to
@@ -976,7 +1313,7 @@ pub enum Justification {
 ///
 /// The default value of `s` is [`Justification::None`].
 pub fn JFY(s: Option<Justification>) -> ControlFunction<'static> {
-    sequence!(02 / 00, 04 / 06, selective default s)
+    sequence!(JFY, selective default s)
 }
 
 /// Valid parameter values to the function [`MC`].
@@ -1008,6 +1345,17 @@ pub enum MediaCopy {
     StartRelaySecondary,
 }
 
+selection!(MediaCopy {
+    BeginTransferToPrimary,
+    BeginTransferFromPrimary,
+    BeginTransferToSecondary,
+    BeginTransferFromSecondary,
+    StopRelayPrimary,
+    StartRelayPrimary,
+    StopRelaySecondary,
+    StartRelaySecondary,
+});
+
 /// Media Copy.
 ///
 /// `MC` is used either to initiate a transfer of data from or to an auxiliary input/output device or to enable or
@@ -1015,7 +1363,7 @@ pub enum MediaCopy {
 ///
 /// The default value for `s` is [`MediaCopy::BeginTransferToPrimary`].
 pub fn MC(s: Option<MediaCopy>) -> ControlFunction<'static> {
-    sequence!(06 / 09, selective default s)
+    sequence!(MC, selective default s)
 }
 
 /// Next Page.
@@ -1026,7 +1374,7 @@ pub fn MC(s: Option<MediaCopy>) -> ControlFunction<'static> {
 ///
 /// The default value for `n` is `1`.
 pub fn NP(n: Option<u32>) -> ControlFunction<'static> {
-    sequence!(05 / 05, numeric n, default 1)
+    sequence!(NP, numeric n, default 1)
 }
 
 /// Valid parameter values to the function [`PEC`].
@@ -1043,6 +1391,12 @@ pub enum PresentationExpandContract {
     Condensed,
 }
 
+selection!(PresentationExpandContract {
+    Normal,
+    Expanded,
+    Condensed,
+});
+
 /// Presentation Expand or Contract.
 ///
 /// `PEC` is used to establish the spacing and the extent of the graphic characters for subsequent text. The spacing is
@@ -1053,7 +1407,7 @@ pub enum PresentationExpandContract {
 ///
 /// The default value for `s` is [`PresentationExpandContract::Normal`].
 pub fn PEC(s: Option<PresentationExpandContract>) -> ControlFunction<'static> {
-    sequence!(02/00, 05/10, selective default s)
+    sequence!(PEC, selective default s)
 }
 
 /// Valid parameter values to the function [`PFS`].
@@ -1109,6 +1463,25 @@ pub enum PageFormat {
     B4LongLines,
 }
 
+selection!(PageFormat {
+    TallBasicText,
+    WideBasicText,
+    TallBasicA4,
+    WideBasicA4,
+    TallLetter,
+    WideLetter,
+    TallExtendedA4,
+    WideExtendedA4,
+    TallLegal,
+    WideLegal,
+    A4ShortLines,
+    A4LongLines,
+    B5ShortLines,
+    B5LongLines,
+    B4ShortLines,
+    B4LongLines,
+});
+
 /// Page Format Selection
 ///
 /// `PFS` is used to establish the available area for the imaging of pages of text based on paper size. The pages are
@@ -1121,7 +1494,7 @@ pub enum PageFormat {
 ///
 /// The default value for `s` is [`PageFormat::TallBasicText`].
 pub fn PFS(s: Option<PageFormat>) -> ControlFunction<'static> {
-    sequence!(02/00, 04/10, selective default s)
+    sequence!(PFS, selective default s)
 }
 
 /// Preceding Page.
@@ -1131,7 +1504,7 @@ pub fn PFS(s: Option<PageFormat>) -> ControlFunction<'static> {
 ///
 /// The default for `n` is `1`.
 pub fn PP(n: Option<u32>) -> ControlFunction<'static> {
-    sequence!(05 / 06, numeric n, default 1)
+    sequence!(PP, numeric n, default 1)
 }
 
 /// Page Position Absolute.
@@ -1141,7 +1514,7 @@ pub fn PP(n: Option<u32>) -> ControlFunction<'static> {
 ///
 /// The default for `n` is `1`.
 pub fn PPA(n: Option<u32>) -> ControlFunction<'static> {
-    sequence!(02 / 00, 05 / 00, numeric n, default 1)
+    sequence!(PPA, numeric n, default 1)
 }
 
 /// Page Position Backward.
@@ -1151,7 +1524,7 @@ pub fn PPA(n: Option<u32>) -> ControlFunction<'static> {
 ///
 /// The default value for `n` is `1`.
 pub fn PPB(n: Option<u32>) -> ControlFunction<'static> {
-    sequence!(02 / 00, 05 / 02, numeric n, default 1)
+    sequence!(PPB, numeric n, default 1)
 }
 
 /// Page Position Forward.
@@ -1161,7 +1534,7 @@ pub fn PPB(n: Option<u32>) -> ControlFunction<'static> {
 ///
 /// The default value for `n` is `1`.
 pub fn PPR(n: Option<u32>) -> ControlFunction<'static> {
-    sequence!(02 / 00, 05 / 01, numeric n, default 1)
+    sequence!(PPR, numeric n, default 1)
 }
 
 /// Valid parameter values to the function [`PTX`].
@@ -1187,6 +1560,15 @@ pub enum ParallelText {
     EndPhonetic,
 }
 
+selection!(ParallelText {
+    End,
+    BeginPrincipal,
+    BeginSupplementary,
+    BeginJapanesePhonetic,
+    BeginChinesePhonetic,
+    EndPhonetic,
+});
+
 /// Parallel Texts.
 ///
 /// `PTX` is used to delimit strings of graphic characters that are communicated one after another in the data stream,
@@ -1229,7 +1611,7 @@ pub enum ParallelText {
 /// be presented in the same line as the Hanzi characters and following the respective Hanzi characters. The Pinyin
 /// characters will then be presented within enclosing paris of parentheses.
 pub fn PTX(s: Option<ParallelText>) -> ControlFunction<'static> {
-    sequence!(05 / 12, selective default s)
+    sequence!(PTX, selective default s)
 }
 
 /// Valid parameter values to the function [`QUAD`].
@@ -1258,6 +1640,16 @@ pub enum Alignment {
     Justify,
 }
 
+selection!(Alignment {
+    LineHome,
+    LineHomeLeader,
+    Centre,
+    CentreLeader,
+    LineLimit,
+    LineLimitLeader,
+    Justify,
+});
+
 /// Quad.
 ///
 /// `QUAD` is used to indicate the end of a string of graphic characters that are to be positioned on a single line
@@ -1275,7 +1667,7 @@ pub enum Alignment {
 ///
 /// The default value for `s` is [`Alignment::LineHome`].
 pub fn QUAD(s: Option<Alignment>) -> ControlFunction<'static> {
-    sequence!(02 / 00, 04 / 08, selective default s)
+    sequence!(QUAD, selective default s)
 }
 
 /// Repeat.
@@ -1286,14 +1678,14 @@ pub fn QUAD(s: Option<Alignment>) -> ControlFunction<'static> {
 ///
 /// The default value for `n` is `1`.
 pub fn REP(n: Option<u32>) -> ControlFunction<'static> {
-    sequence!(06 / 02, numeric n, default 1)
+    sequence!(REP, numeric n, default 1)
 }
 
 /// Reset Mode.
 ///
 /// `RM` causes the modes of the receiving device to be reset as specified by the parameter values.
 pub fn RM(v: Vec<Mode>) -> ControlFunction<'static> {
-    sequence!(06 / 12, variadic selective v)
+    sequence!(RM, variadic selective v)
 }
 
 /// Set Additional Character Representation.
@@ -1310,7 +1702,7 @@ pub fn RM(v: Vec<Mode>) -> ControlFunction<'static> {
 ///
 /// The default value for `n` is 0.
 pub fn SACS(n: Option<u32>) -> ControlFunction<'static> {
-    sequence!(02 / 00, 05 / 12, numeric n, default 0)
+    sequence!(SACS, numeric n, default 0)
 }
 
 /// Valid parameter values to the function [`SAPV`].
@@ -1405,13 +1797,39 @@ pub enum PresentationVariant {
     DesistCharacterForm,
 }
 
+selection!(PresentationVariant {
+    Default,
+    LatinDecimals,
+    ArabicDecimals,
+    MirrorPairs,
+    MirrorFormulae,
+    Isolated,
+    Initial,
+    Medial,
+    Final,
+    DecimalFullStop,
+    DecimalComma,
+    VowelAboveOrBelow,
+    VowelAfterPreceding,
+    ContextualShapeArabicScriptWithLamAleph,
+    ContextualShapeArabicScript,
+    NoMirroring,
+    NoVowels,
+    SlantFollowsStringDirection,
+    NoContextualShapeArabicScript,
+    NoContextualShapeArabicScriptExceptDigits,
+    DeviceDependentDecimalDigits,
+    PersistCharacterForm,
+    DesistCharacterForm,
+});
+
 /// Select Alternative Presentation Variants.
 ///
 /// `SAPV` is used to specify one or more variants for the presentation of subsequent text.
 ///
 /// The default value for `s` is [`PresentationVariant::Default`].
 pub fn SAPV(s: Option<PresentationVariant>) -> ControlFunction<'static> {
-    sequence!(02 / 00, 05 / 13, selective default s)
+    sequence!(SAPV, selective default s)
 }
 
 /// Valid parameter values to the function [`SCO`].
@@ -1443,6 +1861,17 @@ pub enum CharacterOrientation {
     Rotate315,
 }
 
+selection!(CharacterOrientation {
+    Normal,
+    Rotate45,
+    Rotate90,
+    Rotate135,
+    Rotate180,
+    Rotate225,
+    Rotate270,
+    Rotate315,
+});
+
 /// Select Character Orientation.
 ///
 /// `SCO` is used to establish the amount of rotation of the graphic characters following in the data stream. The
@@ -1453,7 +1882,7 @@ pub enum CharacterOrientation {
 ///
 /// The default value for `s` is [`CharacterOrientation::Normal`].
 pub fn SCO(s: Option<CharacterOrientation>) -> ControlFunction<'static> {
-    sequence!(02 / 00, 06 / 05, selective default s)
+    sequence!(SCO, selective default s)
 }
 
 /// Valid parameter values to the function [`SCP`].
@@ -1468,6 +1897,11 @@ pub enum CharacterPath {
     RightToLeft,
 }
 
+selection!(CharacterPath {
+    LefToRight,
+    RightToLeft,
+});
+
 /// Valid parameter values to the function [`SCP`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum CharacterPathScope {
@@ -1495,6 +1929,12 @@ pub enum CharacterPathScope {
     InDataComponent,
 }
 
+selection!(CharacterPathScope {
+    Undefined,
+    InPresentationComponent,
+    InDataComponent,
+});
+
 /// Select Character Path.
 ///
 /// `SCP` is used to select the character path, relative to the line orientation, for the active line (the line that
@@ -1503,7 +1943,7 @@ pub enum CharacterPathScope {
 /// that contains the active data position) in the data component. This takes effect immediately.
 pub fn SCP(s: CharacterPath, t: CharacterPathScope) -> ControlFunction<'static> {
     let (n, m) = ((s as u32), (t as u32));
-    sequence!(02 / 00, 06 / 11, numeric n, numeric m)
+    sequence!(SCP, numeric n, numeric m)
 }
 
 /// Set Character Spacing.
@@ -1517,7 +1957,7 @@ pub fn SCP(s: CharacterPath, t: CharacterPathScope) -> ControlFunction<'static>
 /// The unit in which the parameter value is expressed is that established by the parameter value of SELECT SIZE UNIT
 /// ([`SSU`]).
 pub fn SCS(n: u32) -> ControlFunction<'static> {
-    sequence!(02 / 00, 06 / 07, numeric n)
+    sequence!(SCS, numeric n)
 }
 
 /// Scroll Down.
@@ -1530,7 +1970,7 @@ pub fn SCS(n: u32) -> ControlFunction<'static> {
 ///
 /// The default value for `n` is `1`.
 pub fn SD(n: Option<u32>) -> ControlFunction<'static> {
-    sequence!(05 / 04, numeric n, default 1)
+    sequence!(SD, numeric n, default 1)
 }
 
 /// Valid parameter values to the function [`SDS`].
@@ -1547,6 +1987,12 @@ pub enum StringDirection {
     StartRightToLeft,
 }
 
+selection!(StringDirection {
+    End,
+    StartLeftToRight,
+    StartRightToLeft,
+});
+
 /// Start Directed String.
 ///
 /// `SDS` is used to establish in the data component the beginning and end of a string of characters as well as the
@@ -1579,7 +2025,7 @@ pub enum StringDirection {
 /// The control functions for area definitions ([`DAQ`], [`EPA`][crate::c1::EPA], [`SPA`][crate::c1::SPA],
 /// [`SSA`][crate::c1::SPA]) should not be used within an `SDS` string.
 pub fn SDS(s: Option<StringDirection>) -> ControlFunction<'static> {
-    sequence!(05 / 13, selective default s)
+    sequence!(SDS, selective default s)
 }
 
 /// Valid parameter values to the function [`SEE`].
@@ -1602,6 +2048,14 @@ pub enum EditingExtend {
     All,
 }
 
+selection!(EditingExtend {
+    ActivePage,
+    ActiveLine,
+    ActiveField,
+    QualifiedArea,
+    All,
+});
+
 /// Select Editing Extent.
 ///
 /// `SEE` is used to establish the editing extent for subsequent character or line insertion or deletion. The
@@ -1610,7 +2064,7 @@ pub enum EditingExtend {
 ///
 /// The default value for `s` is [`EditingExtend::ActivePage`].
 pub fn SEE(s: Option<EditingExtend>) -> ControlFunction<'static> {
-    sequence!(05 / 01, selective default s)
+    sequence!(SEE, selective default s)
 }
 
 /// Valid parameter values to the function [`SEF`].
@@ -1625,6 +2079,15 @@ pub enum Load {
     Bin(u32),
 }
 
+impl From<u32> for Load {
+    fn from(value: u32) -> Self {
+        match value {
+            0 => Load::None,
+            bin => Load::Bin(bin),
+        }
+    }
+}
+
 /// Valid parameter values to the function [`SEF`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 #[repr(u32)]
@@ -1637,6 +2100,15 @@ pub enum Stack {
     Stacker(u32),
 }
 
+impl From<u32> for Stack {
+    fn from(value: u32) -> Self {
+        match value {
+            0 => Stack::None,
+            stacker => Stack::Stacker(stacker),
+        }
+    }
+}
+
 /// Sheet Eject And Feed.
 ///
 /// `SEF` causes a sheet of paper to be ejected from a printing device into a specified output stacker and another
@@ -1653,16 +2125,207 @@ pub fn SEF(l: Option<Load>, s: Option<Stack>) -> ControlFunction<'static> {
         Stack::None => 0,
         Stack::Stacker(stacker) => stacker,
     };
-    sequence!(02 / 00, 05 / 09, numeric n, numeric m)
+    sequence!(SEF, numeric n, numeric m)
+}
+
+/// A color of the extended color palettes of [ITU T.416][itu-t416], used by the graphic renditions
+/// [`Foreground`][GraphicRendition::Foreground], [`Background`][GraphicRendition::Background] and
+/// [`UnderlineColor`][GraphicRendition::UnderlineColor].
+///
+/// [`SGR`] selects these colors with several parameters separated by `03/11` (for example `38;5;208`), which is
+/// understood by most terminals. [`ColorEncoding::sgr`] can select them with sub-parameters separated by `03/10` (for
+/// example `38:5:208` or `38:2::255:0:0`) instead. When decoding a [`Sequence`], both forms are
+/// understood.
+///
+/// ```
+/// use ansi_control_codes::control_sequences::{Color, GraphicRendition, SGR};
+///
+/// let orange = SGR(Some(vec![GraphicRendition::Foreground(Color::Indexed(208))]));
+/// assert_eq!(orange.to_string(), "\x1b[38;5;208m");
+///
+/// let red = SGR(Some(vec![GraphicRendition::Background(Color::Rgb(255, 0, 0))]));
+/// assert_eq!(red.to_string(), "\x1b[48;2;255;0;0m");
+/// ```
+///
+/// [itu-t416]: https://www.itu.int/rec/T-REC-T.416-199303-I
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    /// A color of the palette of 256 colors. The first 16 colors are the basic and the bright colors, followed by a
+    /// 6×6×6 color cube and 24 shades of grey.
+    Indexed(u8),
+
+    /// A direct color, given by its red, green and blue components.
+    Rgb(u8, u8, u8),
+}
+
+/// The encoding of the colors of the extended color palettes in [`SGR`].
+///
+/// ```
+/// use ansi_control_codes::control_sequences::{Color, ColorEncoding, GraphicRendition};
+///
+/// let red = Some(vec![GraphicRendition::Foreground(Color::Rgb(255, 0, 0))]);
+/// assert_eq!(
+///     ColorEncoding::Parameters.sgr(red.clone()).to_string(),
+///     "\x1b[38;2;255;0;0m"
+/// );
+/// assert_eq!(
+///     ColorEncoding::SubParameters.sgr(red).to_string(),
+///     "\x1b[38:2::255:0:0m"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorEncoding {
+    /// The selector and the values of the color are separate parameters, separated by `03/11` (for example
+    /// `38;5;208` or `38;2;255;0;0`). This form is understood by most terminals.
+    #[default]
+    Parameters,
+
+    /// The values of the color are sub-parameters of the selector, separated by `03/10` (for example `38:5:208` or
+    /// `38:2::255:0:0`), as defined by [ITU T.416][itu-t416]. The color space identifier of direct colors is omitted.
+    ///
+    /// [itu-t416]: https://www.itu.int/rec/T-REC-T.416-199303-I
+    SubParameters,
+}
+
+impl Color {
+    /// Appends the parameters selecting this color with the `selector` `38`, `48` or `58`, in the given `encoding`.
+    fn push_parameters(self, selector: u32, encoding: ColorEncoding, parameters: &mut Parameters) {
+        // the omitted value of a direct color is its color space identifier, which only exists as a sub-parameter.
+        let (values, length) = match self {
+            Color::Indexed(index) => (
+                [
+                    Some(selector),
+                    Some(5),
+                    Some(u32::from(index)),
+                    None,
+                    None,
+                    None,
+                ],
+                3,
+            ),
+            Color::Rgb(red, green, blue) => (
+                [
+                    Some(selector),
+                    Some(2),
+                    None,
+                    Some(u32::from(red)),
+                    Some(u32::from(green)),
+                    Some(u32::from(blue)),
+                ],
+                6,
+            ),
+        };
+
+        match encoding {
+            ColorEncoding::Parameters => values[..length]
+                .iter()
+                .flatten()
+                .for_each(|value| parameters.push(Parameter::from(*value))),
+            ColorEncoding::SubParameters => {
+                parameters.push(Parameter::new(values[..length].iter().copied()))
+            }
+        }
+    }
+
+    /// Decodes a color from the `values` following the selector `38`, `48` or `58`. The value at each position of
+    /// `values` belongs to the parameter at `index(position)`, which is reported in errors.
+    ///
+    /// If `color_space` is set, the red, green and blue components of a direct color might be preceded by a color space
+    /// identifier, which is ignored. Returns the color and the number of values that were used.
+    fn decode(
+        values: &[Option<u32>],
+        color_space: bool,
+        index: impl Fn(usize) -> usize,
+    ) -> Result<(Color, usize), InvalidSequence> {
+        let component = |position: usize| {
+            let value = values
+                .get(position)
+                .copied()
+                .flatten()
+                .ok_or_else(|| InvalidSequence::MissingParameter(index(position)))?;
+            u8::try_from(value).map_err(|_| InvalidSequence::InvalidSelection {
+                index: index(position),
+                value,
+            })
+        };
+
+        match values.first().copied().flatten() {
+            Some(5) => Ok((Color::Indexed(component(1)?), 2)),
+            Some(2) if color_space && values.len() > 4 => {
+                Ok((Color::Rgb(component(2)?, component(3)?, component(4)?), 5))
+            }
+            Some(2) => Ok((Color::Rgb(component(1)?, component(2)?, component(3)?), 4)),
+            Some(value) => Err(InvalidSequence::InvalidSelection {
+                index: index(0),
+                value,
+            }),
+            None => Err(InvalidSequence::MissingParameter(index(0))),
+        }
+    }
 }
 
+/// The style of underlines, selected by [`GraphicRendition::UnderlineStyle`].
+///
+/// The styles are selected by a sub-parameter of the graphic rendition `4`, for example `4:3` for curly underlines.
+/// This is not part of ECMA-48, but a common extension that is supported by many terminals.
+///
+/// ```
+/// use ansi_control_codes::control_sequences::{GraphicRendition, UnderlineStyle, SGR};
+///
+/// let curly = SGR(Some(vec![GraphicRendition::UnderlineStyle(UnderlineStyle::Curly)]));
+/// assert_eq!(curly.to_string(), "\x1b[4:3m");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum UnderlineStyle {
+    /// Not underlined.
+    #[default]
+    None = 0,
+
+    /// Singly underlined.
+    Single,
+
+    /// Doubly underlined.
+    Double,
+
+    /// Curly underlined.
+    Curly,
+
+    /// Dotted underlined.
+    Dotted,
+
+    /// Dashed underlined.
+    Dashed,
+}
+
+selection!(UnderlineStyle {
+    None,
+    Single,
+    Double,
+    Curly,
+    Dotted,
+    Dashed,
+});
+
 /// Valid parameter values to the function [`SGR`].
+///
+/// ## Common Extensions
+///
+/// Some graphic renditions are not part of ECMA-48, but are supported by almost all terminals:
+///
+/// - [`UnderlineStyle`][GraphicRendition::UnderlineStyle] (`4:0` to `4:5`) selects the style of underlines with a
+///   sub-parameter.
+/// - [`UnderlineColor`][GraphicRendition::UnderlineColor] (`58`) selects the color of underlines, and
+///   [`DefaultUnderlineColor`][GraphicRendition::DefaultUnderlineColor] (`59`) resets it.
+/// - The bright foreground colors [`BrightBlackForeground`][GraphicRendition::BrightBlackForeground] to
+///   [`BrightWhiteForeground`][GraphicRendition::BrightWhiteForeground] (`90` to `97`) and the bright background colors
+///   [`BrightBlackBackground`][GraphicRendition::BrightBlackBackground] to
+///   [`BrightWhiteBackground`][GraphicRendition::BrightWhiteBackground] (`100` to `107`) were introduced by aixterm.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum GraphicRendition {
     /// Default rendition (implementation-defined), cancels the effect of any preceding occurrence of [`SGR`] in the
     /// data stream regardless of the setting of the GRAPHIC RENDITION COMBINATION MODE ([`GRCM`][crate::modes::GRCM]).
     #[default]
-    Default = 0,
+    Default,
 
     /// Bold or increased intensity.
     HighIntensity,
@@ -1676,6 +2339,9 @@ pub enum GraphicRendition {
     /// Singly underlined.
     Underlined,
 
+    /// Underlined in the given style, selected by a sub-parameter of `4`.
+    UnderlineStyle(UnderlineStyle),
+
     /// Slowly blinking (less than 150 per minute).
     SlowlyBlinking,
 
@@ -1740,7 +2406,7 @@ pub enum GraphicRendition {
     NotBlinking,
 
     /// Positive Image.
-    Positive = 27,
+    Positive,
 
     /// Revealed characters.
     Revealed,
@@ -1772,8 +2438,11 @@ pub enum GraphicRendition {
     /// White display.
     WhiteForeground,
 
+    /// Foreground color of the extended color palettes of ITU T.416.
+    Foreground(Color),
+
     /// Default display color (implementation specific).
-    DefaultForeground = 39,
+    DefaultForeground,
 
     /// Black background.
     BlackBackground,
@@ -1799,11 +2468,14 @@ pub enum GraphicRendition {
     /// White background.
     WhiteBackground,
 
+    /// Background color of the extended color palettes of ITU T.416.
+    Background(Color),
+
     /// Default background color (implementation specific).
-    DefaultBackground = 49,
+    DefaultBackground,
 
     /// Framed.
-    Framed = 51,
+    Framed,
 
     /// Encircled.
     Encircled,
@@ -1817,8 +2489,14 @@ pub enum GraphicRendition {
     /// Not overlined,
     NotOverlined,
 
+    /// Underline color of the extended color palettes of ITU T.416.
+    UnderlineColor(Color),
+
+    /// Default underline color (implementation specific).
+    DefaultUnderlineColor,
+
     /// Ideogram underline or right side line.
-    IdeogramUnderline = 60,
+    IdeogramUnderline,
 
     /// Ideogram double underline or double line on the right side.
     IdeogramDoubleUnderline,
@@ -1828,6 +2506,175 @@ pub enum GraphicRendition {
 
     /// Cancel Ideogram rendition settings.
     CancelIdeogramRendition,
+
+    /// Bright black display.
+    BrightBlackForeground,
+
+    /// Bright red display.
+    BrightRedForeground,
+
+    /// Bright green display.
+    BrightGreenForeground,
+
+    /// Bright yellow display.
+    BrightYellowForeground,
+
+    /// Bright blue display.
+    BrightBlueForeground,
+
+    /// Bright magenta display.
+    BrightMagentaForeground,
+
+    /// Bright cyan display.
+    BrightCyanForeground,
+
+    /// Bright white display.
+    BrightWhiteForeground,
+
+    /// Bright black background.
+    BrightBlackBackground,
+
+    /// Bright red background.
+    BrightRedBackground,
+
+    /// Bright green background.
+    BrightGreenBackground,
+
+    /// Bright yellow background.
+    BrightYellowBackground,
+
+    /// Bright blue background.
+    BrightBlueBackground,
+
+    /// Bright magenta background.
+    BrightMagentaBackground,
+
+    /// Bright cyan background.
+    BrightCyanBackground,
+
+    /// Bright white background.
+    BrightWhiteBackground,
+}
+
+/// Implements the conversions between graphic renditions and their parameter values, and defines the exported macro
+/// `__graphic_rendition` that is used by [`sgr!`].
+macro_rules! graphic_renditions {
+    ($($variant:ident = $value:literal),* $(,)?) => {
+        impl GraphicRendition {
+            /// Returns the parameter value selecting this graphic rendition. Colors of the extended color palettes are
+            /// selected by further values following it.
+            fn value(self) -> u32 {
+                match self {
+                    $(GraphicRendition::$variant => $value,)*
+                    GraphicRendition::Foreground(_) => 38,
+                    GraphicRendition::Background(_) => 48,
+                    GraphicRendition::UnderlineColor(_) => 58,
+                    GraphicRendition::UnderlineStyle(_) => 4,
+                }
+            }
+        }
+
+        impl TryFrom<u32> for GraphicRendition {
+            type Error = crate::InvalidSelection;
+
+            /// Converts a parameter value into a graphic rendition. Colors of the extended color palettes are selected
+            /// by more than one value, their selectors `38`, `48` and `58` are rejected.
+            fn try_from(value: u32) -> Result<Self, Self::Error> {
+                match value {
+                    $($value => Ok(GraphicRendition::$variant),)*
+                    value => Err(crate::InvalidSelection { value }),
+                }
+            }
+        }
+
+        /// The parameter value of each graphic rendition, used by [`sgr!`].
+        #[doc(hidden)]
+        #[macro_export]
+        macro_rules! __graphic_rendition {
+            $(($variant) => { $value };)*
+        }
+
+        /// Each graphic rendition that is selected by a single parameter value, and its parameter value.
+        #[cfg(test)]
+        pub(crate) const GRAPHIC_RENDITIONS: &[(GraphicRendition, u32)] =
+            &[$((GraphicRendition::$variant, $value)),*];
+    };
+}
+
+graphic_renditions! {
+    Default = 0,
+    HighIntensity = 1,
+    LowIntensity = 2,
+    Italicized = 3,
+    Underlined = 4,
+    SlowlyBlinking = 5,
+    RapidlyBlinking = 6,
+    Negative = 7,
+    Concealed = 8,
+    CrossedOut = 9,
+    PrimaryFont = 10,
+    FirstAlternativeFont = 11,
+    SecondAlternativeFont = 12,
+    ThirdAlternativeFont = 13,
+    ForthAlternativeFont = 14,
+    FifthAlternativeFont = 15,
+    SixthAlternativeFont = 16,
+    SeventhAlternativeFont = 17,
+    EighthAlternativeFont = 18,
+    NinthAlternativeFont = 19,
+    Fraktur = 20,
+    DoublyUnderlined = 21,
+    NormalIntensity = 22,
+    NormalStyle = 23,
+    NotUnderlined = 24,
+    NotBlinking = 25,
+    Positive = 27,
+    Revealed = 28,
+    NotCrossedOut = 29,
+    BlackForeground = 30,
+    RedForeground = 31,
+    GreenForeground = 32,
+    YellowForeground = 33,
+    BlueForeground = 34,
+    MagentaForeground = 35,
+    CyanForeground = 36,
+    WhiteForeground = 37,
+    DefaultForeground = 39,
+    BlackBackground = 40,
+    RedBackground = 41,
+    GreenBackground = 42,
+    YellowBackground = 43,
+    BlueBackground = 44,
+    MagentaBackground = 45,
+    CyanBackground = 46,
+    WhiteBackground = 47,
+    DefaultBackground = 49,
+    Framed = 51,
+    Encircled = 52,
+    Overlined = 53,
+    NotFramed = 54,
+    NotOverlined = 55,
+    DefaultUnderlineColor = 59,
+    IdeogramUnderline = 60,
+    IdeogramDoubleUnderline = 61,
+    IdeogramStressMarking = 62,
+    CancelIdeogramRendition = 63,
+    BrightBlackForeground = 90,
+    BrightRedForeground = 91,
+    BrightGreenForeground = 92,
+    BrightYellowForeground = 93,
+    BrightBlueForeground = 94,
+    BrightMagentaForeground = 95,
+    BrightCyanForeground = 96,
+    BrightWhiteForeground = 97,
+    BrightBlackBackground = 100,
+    BrightRedBackground = 101,
+    BrightGreenBackground = 102,
+    BrightYellowBackground = 103,
+    BrightBlueBackground = 104,
+    BrightMagentaBackground = 105,
+    BrightCyanBackground = 106,
+    BrightWhiteBackground = 107,
 }
 
 /// Select Graphic Rendition.
@@ -1843,8 +2690,34 @@ pub enum GraphicRendition {
 ///
 /// The usable combinations of parameter values are determined by the implementation.
 pub fn SGR(s: Option<Vec<GraphicRendition>>) -> ControlFunction<'static> {
-    let g = s.unwrap_or(vec![Default::default()]);
-    sequence!(06 / 13, variadic selective g)
+    ColorEncoding::default().sgr(s)
+}
+
+impl ColorEncoding {
+    /// Select Graphic Rendition, with the colors of the extended color palettes in this encoding.
+    ///
+    /// Like [`SGR`], but selects the colors of [`Foreground`][GraphicRendition::Foreground],
+    /// [`Background`][GraphicRendition::Background] and [`UnderlineColor`][GraphicRendition::UnderlineColor] in this
+    /// form.
+    pub fn sgr(self, s: Option<Vec<GraphicRendition>>) -> ControlFunction<'static> {
+        let g = s.unwrap_or_else(|| vec![Default::default()]);
+        let mut parameters = Parameters::new();
+        for graphic_rendition in g {
+            match graphic_rendition {
+                GraphicRendition::UnderlineStyle(style) => parameters.push(Parameter::new([
+                    Some(graphic_rendition.value()),
+                    Some(style as u32),
+                ])),
+                GraphicRendition::Foreground(color)
+                | GraphicRendition::Background(color)
+                | GraphicRendition::UnderlineColor(color) => {
+                    color.push_parameters(graphic_rendition.value(), self, &mut parameters)
+                }
+                _ => parameters.push(Parameter::from(graphic_rendition.value())),
+            }
+        }
+        ControlFunction::new_sequence(function::SGR, parameters)
+    }
 }
 
 /// Valid parameter values to the function [`SHS`].
@@ -1873,6 +2746,16 @@ pub enum CharacterSpacing {
     FourCharacters,
 }
 
+selection!(CharacterSpacing {
+    TenCharacters,
+    TwelveCharacters,
+    FifteenCharacters,
+    SixCharacters,
+    ThreeCharacters,
+    NineCharacters,
+    FourCharacters,
+});
+
 /// Select Character Spacing.
 ///
 /// `SHS` is used to establish the character spacing for subsequent text. The established spacing remains in effect
@@ -1881,7 +2764,7 @@ pub enum CharacterSpacing {
 ///
 /// The default value for `s` is [`CharacterSpacing::TenCharacters`].
 pub fn SHS(s: Option<CharacterSpacing>) -> ControlFunction<'static> {
-    sequence!(02 / 00, 04 / 11, selective default s)
+    sequence!(SHS, selective default s)
 }
 
 /// Valid parameter values to the function [`SIMD`].
@@ -1895,6 +2778,8 @@ pub enum MovementDirection {
     Opposite,
 }
 
+selection!(MovementDirection { Normal, Opposite });
+
 /// Select Implicit Movement Direction.
 ///
 /// `SIMD` is used to select the direction of implicit movement of the data position relative to the character
@@ -1902,7 +2787,7 @@ pub enum MovementDirection {
 ///
 /// The default value of `s` is [`MovementDirection::Normal`].
 pub fn SIMD(s: Option<MovementDirection>) -> ControlFunction<'static> {
-    sequence!(05 / 14, selective default s)
+    sequence!(SIMD, selective default s)
 }
 
 /// Scroll Left.
@@ -1915,7 +2800,7 @@ pub fn SIMD(s: Option<MovementDirection>) -> ControlFunction<'static> {
 ///
 /// The default value for `n` is `1`.
 pub fn SL(n: Option<u32>) -> ControlFunction<'static> {
-    sequence!(02 / 00, 04 / 00, numeric n, default 1)
+    sequence!(SL, numeric n, default 1)
 }
 
 /// Set Line Home.
@@ -1937,7 +2822,7 @@ pub fn SL(n: Option<u32>) -> ControlFunction<'static> {
 /// The established position is called the line home position and remains in effect until the next occurrence of `SLH`
 /// in the data stream.
 pub fn SLH(n: u32) -> ControlFunction<'static> {
-    sequence!(02 / 00, 05 / 05, numeric n)
+    sequence!(SLH, numeric n)
 }
 
 /// Set Line Limit.
@@ -1960,7 +2845,7 @@ pub fn SLH(n: u32) -> ControlFunction<'static> {
 /// The established position is called the line limit position and remains in effect until the next occurrence of `SLL`
 /// in the data stream.
 pub fn SLL(n: u32) -> ControlFunction<'static> {
-    sequence!(02 / 00, 05 / 06, numeric n)
+    sequence!(SLL, numeric n)
 }
 
 /// Set Line Spacing.
@@ -1973,14 +2858,14 @@ pub fn SLL(n: u32) -> ControlFunction<'static> {
 /// The unit in which the parameter value is expressed is that established by the parameter value of SELECT SIZE UNIT
 /// ([`SSU`]).
 pub fn SLS(n: u32) -> ControlFunction<'static> {
-    sequence!(02 / 00, 06 / 08, numeric n)
+    sequence!(SLS, numeric n)
 }
 
 /// Set Mode.
 ///
 /// `SM` causes the modes of the receiving device to be set as specified by the parameter values.
 pub fn SM(s: Vec<Mode>) -> ControlFunction<'static> {
-    sequence!(06 / 08, variadic selective s)
+    sequence!(SM, variadic selective s)
 }
 
 /// Valid parameter values to the function [`SPD`].
@@ -2012,6 +2897,17 @@ pub enum PresentationDirection {
     VerticalLinesRightToLeftBottomToTop,
 }
 
+selection!(PresentationDirection {
+    HorizontalLinesTopToBottomLeftToRight,
+    VerticalLinesRightToLeftTopToBottom,
+    VerticalLinesLeftToRightTopToBottom,
+    HorizontalLinesTopToBottomRightToLeft,
+    VerticalLinesLeftToRightBottomToTop,
+    HorizontalLinesBottomToTopRightToLeft,
+    HorizontalLinesBottomToTopLefToRight,
+    VerticalLinesRightToLeftBottomToTop,
+});
+
 /// Valid parameter values to the function [`SPD`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum PresentationDirectionScope {
@@ -2038,6 +2934,12 @@ pub enum PresentationDirectionScope {
     InDataComponent,
 }
 
+selection!(PresentationDirectionScope {
+    Undefined,
+    InPresentationComponent,
+    InDataComponent,
+});
+
 /// Select Presentation Directions.
 ///
 /// `SPD` is used to select the line orientation, the line progression, and the character path in the presentation
@@ -2053,7 +2955,7 @@ pub fn SPD(
     s: Option<PresentationDirection>,
     t: Option<PresentationDirectionScope>,
 ) -> ControlFunction<'static> {
-    sequence!(02 / 00, 05 / 03, selective default s, selective default t)
+    sequence!(SPD, selective default s, selective default t)
 }
 
 /// Set Page Home.
@@ -2074,7 +2976,7 @@ pub fn SPD(
 /// The established position is called the page home position and remains in effect until the next occurrence of `SPH`
 /// in the data stream.
 pub fn SPH(n: u32) -> ControlFunction<'static> {
-    sequence!(02 / 00, 06 / 09, numeric n)
+    sequence!(SPH, numeric n)
 }
 
 /// Spacing Increment.
@@ -2090,7 +2992,7 @@ pub fn SPH(n: u32) -> ControlFunction<'static> {
 /// The unit in which the parameter values are expressed is that established by the parameter value of SELECT SIZE UNIT
 /// ([`SSU`]).
 pub fn SPI(l: u32, c: u32) -> ControlFunction<'static> {
-    sequence!(02 / 00, 04 / 07, numeric l, numeric c)
+    sequence!(SPI, numeric l, numeric c)
 }
 
 /// Set Page Limit.
@@ -2108,7 +3010,7 @@ pub fn SPI(l: u32, c: u32) -> ControlFunction<'static> {
 /// The established position is called the page limit position and remains in effect until the next occurrence of `SPL`
 /// in the data stream.
 pub fn SPL(n: u32) -> ControlFunction<'static> {
-    sequence!(02 / 00, 06 / 10, numeric n)
+    sequence!(SPL, numeric n)
 }
 
 /// Valid parameter values to the function [`SPQR`].
@@ -2125,6 +3027,12 @@ pub enum PrintQuality {
     LowQualityHighSpeed,
 }
 
+selection!(PrintQuality {
+    HighQualityLowSpeed,
+    MediumQualityMediumSpeed,
+    LowQualityHighSpeed,
+});
+
 /// Select Print Quality and Rapidity.
 ///
 /// `SPQR` is used to select the relative print quality and the print speed for devices where the output quality and
@@ -2133,7 +3041,7 @@ pub enum PrintQuality {
 ///
 /// The default value of `s` is [`PrintQuality::HighQualityLowSpeed`].
 pub fn SPQR(s: Option<PrintQuality>) -> ControlFunction<'static> {
-    sequence!(02 / 00, 05 / 08, selective default s)
+    sequence!(SPQR, selective default s)
 }
 
 /// Scroll Right.
@@ -2146,7 +3054,7 @@ pub fn SPQR(s: Option<PrintQuality>) -> ControlFunction<'static> {
 ///
 /// The default value for `n` is `1`.
 pub fn SR(n: Option<u32>) -> ControlFunction<'static> {
-    sequence!(02 / 00, 05 / 08, numeric n, default 1)
+    sequence!(SR, numeric n, default 1)
 }
 
 /// Set Reduced Character Separation.
@@ -2164,7 +3072,7 @@ pub fn SR(n: Option<u32>) -> ControlFunction<'static> {
 ///
 /// The default value of `n` is `0`.
 pub fn SRCS(n: Option<u32>) -> ControlFunction<'static> {
-    sequence!(02 / 00, 06 / 06, numeric n, default 0)
+    sequence!(SRCS, numeric n, default 0)
 }
 
 /// Valid parameter values to the function [`SRS`].
@@ -2178,6 +3086,8 @@ pub enum ReversedString {
     Start,
 }
 
+selection!(ReversedString { End, Start });
+
 /// Start Reversed String.
 ///
 /// `SRS` is used to establish in the data component the beginning and the end of a string of characters as well as
@@ -2209,7 +3119,7 @@ pub enum ReversedString {
 /// The control functions for area definition ([`DAQ`], [`EPA`][crate::c1::EPA], [`ESA`][crate::c1::ESA],
 /// [`SPA`][crate::c1::SPA], [`SSA`][crate::c1::SSA]) should not be used within an `SRS` string.
 pub fn SRS(s: Option<ReversedString>) -> ControlFunction<'static> {
-    sequence!(05 / 11, selective default s)
+    sequence!(SRS, selective default s)
 }
 
 /// Valid parameter values to the function [`SSU`].
@@ -2244,6 +3154,18 @@ pub enum SizeUnit {
     Decipoint,
 }
 
+selection!(SizeUnit {
+    Character,
+    Millimetre,
+    ComputerDecipoint,
+    Decidot,
+    Mil,
+    BasicMeasuringUnit,
+    Micrometer,
+    Pixel,
+    Decipoint,
+});
+
 /// Select Size Unit.
 ///
 /// `SSU` is used to establish the unit in which the numeric parameters of certain control functions are expressed. The
@@ -2251,7 +3173,7 @@ pub enum SizeUnit {
 ///
 /// Default value of `s` is [`SizeUnit::Character`].
 pub fn SSU(s: Option<SizeUnit>) -> ControlFunction<'static> {
-    sequence!(02 / 00, 04 / 09, selective default s)
+    sequence!(SSU, selective default s)
 }
 
 /// Set Space Width.
@@ -2272,7 +3194,7 @@ pub fn SSU(s: Option<SizeUnit>) -> ControlFunction<'static> {
 /// the current font has constant spacing, or is specified by the nominal width of the character `SPACE` in the current
 /// font if that font has proportional spacing.
 pub fn SSW(n: u32) -> ControlFunction<'static> {
-    sequence!(02 / 00, 05 / 11, numeric n)
+    sequence!(SSW, numeric n)
 }
 
 /// Selective Tabulation.
@@ -2283,7 +3205,7 @@ pub fn SSW(n: u32) -> ControlFunction<'static> {
 /// The use of this control function and means of specifying a list of tabulation stop to be referenced by the control
 /// function are specified in other standards, for example ISO 8613-6.
 pub fn STAB(s: u32) -> ControlFunction<'static> {
-    sequence!(02 / 00, 05 / 14, numeric s)
+    sequence!(STAB, numeric s)
 }
 
 /// Scroll Up.
@@ -2296,7 +3218,7 @@ pub fn STAB(s: u32) -> ControlFunction<'static> {
 ///
 /// The default value for `n` is `1`.
 pub fn SU(n: Option<u32>) -> ControlFunction<'static> {
-    sequence!(05 / 03, numeric n, default 1)
+    sequence!(SU, numeric n, default 1)
 }
 
 /// Valid parameter values to the function [`SLS`].
@@ -2334,6 +3256,19 @@ pub enum LineSpacing {
     TwoLinesPer25,
 }
 
+selection!(LineSpacing {
+    SixLinesPer25,
+    FourLinesPer25,
+    ThreeLinesPer25,
+    TwelveLinesPer25,
+    EightLinesPer25,
+    SixLinesPer30,
+    FourLinesPer30,
+    ThreeLinesPer30,
+    TwelveLinesPer30,
+    TwoLinesPer25,
+});
+
 /// Select Line Spacing.
 ///
 /// `SVS` is used to establish the line spacing for subsequent text. The established spacing remains in effect until the
@@ -2341,7 +3276,7 @@ pub enum LineSpacing {
 ///
 /// The default value for `s` is [`LineSpacing::SixLinesPer25`].
 pub fn SVS(s: Option<LineSpacing>) -> ControlFunction<'static> {
-    sequence!(02 / 00, 04 / 12, selective default s)
+    sequence!(SVS, selective default s)
 }
 
 /// Tabulation Aligned Centred.
@@ -2355,7 +3290,7 @@ pub fn SVS(s: Option<LineSpacing>) -> ControlFunction<'static> {
 /// first graphic character and the (leading edge of the) last graphic character are at approximately equal distances
 /// from the tabulation stop.
 pub fn TAC(n: u32) -> ControlFunction<'static> {
-    sequence!(02 / 00, 06 / 02, numeric n)
+    sequence!(TAC, numeric n)
 }
 
 /// Tabulation Aligned Leading Edge.
@@ -2368,7 +3303,7 @@ pub fn TAC(n: u32) -> ControlFunction<'static> {
 /// A text string aligned with a tabulation stop set by `TALE` will be positioned so that the (leading edge of the) last
 /// graphic character of the string is placed at the tabulation stop.
 pub fn TALE(n: u32) -> ControlFunction<'static> {
-    sequence!(02 / 00, 06 / 01, numeric n)
+    sequence!(TALE, numeric n)
 }
 
 /// Tabulation Aligned Trailing Edge.
@@ -2381,7 +3316,7 @@ pub fn TALE(n: u32) -> ControlFunction<'static> {
 /// A text string aligned with a tabulation stop set by `TATE` will be positioned so that the (trailing edge of the)
 /// first graphic character of the string is placed at the tabulation stop.
 pub fn TATE(n: u32) -> ControlFunction<'static> {
-    sequence!(02 / 00, 06 / 00, numeric n)
+    sequence!(TATE, numeric n)
 }
 
 /// Valid parameter values to the function [`TBC`].
@@ -2407,6 +3342,15 @@ pub enum ClearTabulation {
     AllTabulationStops,
 }
 
+selection!(ClearTabulation {
+    CharacterTabulationStopActivePosition,
+    LineTabulationStopActiveLine,
+    AllCharacterTabulationStopsActiveLine,
+    AllCharacterTabulationStops,
+    AllLineTabulationStops,
+    AllTabulationStops,
+});
+
 /// Tabulation Clear.
 ///
 /// `TBC` causes one or more tabulation stops in the presentation component to be cleared, depending on the parameter
@@ -2414,7 +3358,7 @@ pub enum ClearTabulation {
 ///
 /// The default value for `s` is [`ClearTabulation::CharacterTabulationStopActivePosition`].
 pub fn TBC(s: Option<ClearTabulation>) -> ControlFunction<'static> {
-    sequence!(06 / 07, selective default s)
+    sequence!(TBC, selective default s)
 }
 
 /// Tabulation Centred on Character.
@@ -2437,7 +3381,7 @@ pub fn TBC(s: Option<ClearTabulation>) -> ControlFunction<'static> {
 /// The default value of `m` is `32`.
 pub fn TCC(n: u32, m: Option<u32>) -> ControlFunction<'static> {
     let k = m.unwrap_or(32);
-    sequence!(02 / 00, 06 / 03, numeric n, numeric k)
+    sequence!(TCC, numeric n, numeric k)
 }
 
 /// Tabulation Stop Remove.
@@ -2447,7 +3391,7 @@ pub fn TCC(n: u32, m: Option<u32>) -> ControlFunction<'static> {
 /// not affect other tabulation stops.
 ///
 pub fn TSR(n: u32) -> ControlFunction<'static> {
-    sequence!(02 / 00, 06 /04, numeric n)
+    sequence!(TSR, numeric n)
 }
 
 /// Thin Space Specification.
@@ -2460,7 +3404,7 @@ pub fn TSR(n: u32) -> ControlFunction<'static> {
 /// The unit in which the parameter value is expressed is that established by the parameter value of SELECT SIZE UNIT
 /// ([`SSU`]).
 pub fn TSS(n: u32) -> ControlFunction<'static> {
-    sequence!(02 / 00, 04 / 05, numeric n)
+    sequence!(TSS, numeric n)
 }
 
 /// Line Position Absolute.
@@ -2470,7 +3414,7 @@ pub fn TSS(n: u32) -> ControlFunction<'static> {
 ///
 /// The default value for `n` is `1`.
 pub fn VPA(n: Option<u32>) -> ControlFunction<'static> {
-    sequence!(06 / 04, numeric n, default 1)
+    sequence!(VPA, numeric n, default 1)
 }
 
 /// Line Position Backward.
@@ -2480,7 +3424,7 @@ pub fn VPA(n: Option<u32>) -> ControlFunction<'static> {
 ///
 /// The default value for `n` is `1`.
 pub fn VPB(n: Option<u32>) -> ControlFunction<'static> {
-    sequence!(06 / 11, numeric n, default 1)
+    sequence!(VPB, numeric n, default 1)
 }
 
 /// Line Position Forward.
@@ -2490,5 +3434,670 @@ pub fn VPB(n: Option<u32>) -> ControlFunction<'static> {
 ///
 /// The default value for `n` is `1`.
 pub fn VPR(n: Option<u32>) -> ControlFunction<'static> {
-    sequence!(06 / 05, numeric n, default 1)
+    sequence!(VPR, numeric n, default 1)
+}
+
+/// A standardized control sequence, together with its decoded parameters.
+///
+/// A [`ControlFunction`] that represents one of the control sequences of this module can be converted into a
+/// `Sequence` with [`TryFrom`]. All parameters are decoded into their numeric values or into the enums of their
+/// selective parameter values. Omitted parameters are replaced by their default values.
+///
+/// ```
+/// use ansi_control_codes::control_sequences::{ErasePage, Sequence, CUP, ED};
+///
+/// assert_eq!(
+///     Sequence::try_from(&CUP(Some(5), None)),
+///     Ok(Sequence::CursorPosition { line: 5, column: 1 })
+/// );
+/// assert_eq!(
+///     Sequence::try_from(&ED(Some(ErasePage::BeginToEnd))),
+///     Ok(Sequence::EraseInPage(ErasePage::BeginToEnd))
+/// );
+/// ```
+///
+/// Sub-parameters and parameters that exceed the number of parameters of a control sequence are ignored.
+///
+/// Control sequences with private parameters are not standardized. Only the common extensions that set and reset the
+/// DEC private modes ([`PrivateMode`]) are decoded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Sequence {
+    /// Active Position Report [`CPR`].
+    ActivePositionReport {
+        /// The line position.
+        line: u32,
+        /// The character position.
+        column: u32,
+    },
+
+    /// Character And Line Position [`HVP`].
+    CharacterAndLinePosition {
+        /// The line position.
+        line: u32,
+        /// The character position.
+        column: u32,
+    },
+
+    /// Character Position Absolute [`HPA`].
+    CharacterPositionAbsolute(u32),
+
+    /// Character Position Backward [`HPB`].
+    CharacterPositionBackward(u32),
+
+    /// Character Position Forward [`HPR`].
+    CharacterPositionForward(u32),
+
+    /// Cursor Backward Tabulation [`CBT`].
+    CursorBackwardTabulation(u32),
+
+    /// Cursor Character Absolute [`CHA`].
+    CursorCharacterAbsolute(u32),
+
+    /// Cursor Down [`CUD`].
+    CursorDown(u32),
+
+    /// Cursor Forward Tabulation [`CHT`].
+    CursorForwardTabulation(u32),
+
+    /// Cursor Left [`CUB`].
+    CursorLeft(u32),
+
+    /// Cursor Line Tabulation [`CVT`].
+    CursorLineTabulation(u32),
+
+    /// Cursor Next Line [`CNL`].
+    CursorNextLine(u32),
+
+    /// Cursor Position [`CUP`].
+    CursorPosition {
+        /// The line position.
+        line: u32,
+        /// The character position.
+        column: u32,
+    },
+
+    /// Cursor Preceding Line [`CPL`].
+    CursorPrecedingLine(u32),
+
+    /// Cursor Right [`CUF`].
+    CursorRight(u32),
+
+    /// Cursor Tabulation Control [`CTC`].
+    CursorTabulationControl(TabulationControl),
+
+    /// Cursor Up [`CUU`].
+    CursorUp(u32),
+
+    /// Define Area Qualification [`DAQ`].
+    DefineAreaQualification(AreaQualification),
+
+    /// Delete Character [`DCH`].
+    DeleteCharacter(u32),
+
+    /// Delete Line [`DL`].
+    DeleteLine(u32),
+
+    /// Device Attributes [`DA`].
+    DeviceAttributes(DeviceAttributes),
+
+    /// Device Status Report [`DSR`].
+    DeviceStatusReport(DeviceStatusReport),
+
+    /// Dimension Text Area [`DTA`].
+    DimensionTextArea {
+        /// The dimension in the direction perpendicular to the line orientation.
+        perpendicular: u32,
+        /// The dimension in the direction parallel to the line orientation.
+        parallel: u32,
+    },
+
+    /// Erase Character [`ECH`].
+    EraseCharacter(u32),
+
+    /// Erase In Area [`EA`].
+    EraseInArea(EraseArea),
+
+    /// Erase In Field [`EF`].
+    EraseInField(EraseField),
+
+    /// Erase In Line [`EL`].
+    EraseInLine(EraseLine),
+
+    /// Erase In Page [`ED`].
+    EraseInPage(ErasePage),
+
+    /// Font Selection [`FNT`].
+    FontSelection {
+        /// The primary or alternative font concerned.
+        font: Font,
+        /// The identification of the character font.
+        identification: u32,
+    },
+
+    /// Function Key [`FNK`].
+    FunctionKey(u32),
+
+    /// Graphic Character Combination [`GCC`].
+    GraphicCharacterCombination(GraphicCharacterCombination),
+
+    /// Graphic Size Modification [`GSM`].
+    GraphicSizeModification {
+        /// The height as a percentage of the height established by [`GSS`].
+        height: u32,
+        /// The width as a percentage of the width established by [`GSS`].
+        width: u32,
+    },
+
+    /// Graphic Size Selection [`GSS`].
+    GraphicSizeSelection(u32),
+
+    /// Identify Device Control String [`IDCS`].
+    IdentifyDeviceControlString(IdentifyDeviceControlString),
+
+    /// Identify Graphic Subrepertoire [`IGS`].
+    IdentifyGraphicSubrepertoire(u32),
+
+    /// Insert Character [`ICH`].
+    InsertCharacter(u32),
+
+    /// Insert Line [`IL`].
+    InsertLine(u32),
+
+    /// Justify [`JFY`].
+    Justify(Justification),
+
+    /// Line Position Absolute [`VPA`].
+    LinePositionAbsolute(u32),
+
+    /// Line Position Backward [`VPB`].
+    LinePositionBackward(u32),
+
+    /// Line Position Forward [`VPR`].
+    LinePositionForward(u32),
+
+    /// Media Copy [`MC`].
+    MediaCopy(MediaCopy),
+
+    /// Next Page [`NP`].
+    NextPage(u32),
+
+    /// Page Format Selection [`PFS`].
+    PageFormatSelection(PageFormat),
+
+    /// Page Position Absolute [`PPA`].
+    PagePositionAbsolute(u32),
+
+    /// Page Position Backward [`PPB`].
+    PagePositionBackward(u32),
+
+    /// Page Position Forward [`PPR`].
+    PagePositionForward(u32),
+
+    /// Parallel Texts [`PTX`].
+    ParallelTexts(ParallelText),
+
+    /// Preceding Page [`PP`].
+    PrecedingPage(u32),
+
+    /// Presentation Expand Or Contract [`PEC`].
+    PresentationExpandOrContract(PresentationExpandContract),
+
+    /// Quad [`QUAD`].
+    Quad(Alignment),
+
+    /// Repeat [`REP`].
+    Repeat(u32),
+
+    /// Reset Mode [`RM`].
+    ResetMode(Vec<Mode>),
+
+    /// Reset DEC Private Mode, see [`PrivateMode::reset`].
+    ResetPrivateMode(Vec<PrivateMode>),
+
+    /// Scroll Down [`SD`].
+    ScrollDown(u32),
+
+    /// Scroll Left [`SL`].
+    ScrollLeft(u32),
+
+    /// Scroll Right [`SR`].
+    ScrollRight(u32),
+
+    /// Scroll Up [`SU`].
+    ScrollUp(u32),
+
+    /// Select Alternative Presentation Variants [`SAPV`].
+    SelectAlternativePresentationVariants(PresentationVariant),
+
+    /// Select Character Orientation [`SCO`].
+    SelectCharacterOrientation(CharacterOrientation),
+
+    /// Select Character Path [`SCP`].
+    SelectCharacterPath {
+        /// The character path.
+        path: CharacterPath,
+        /// The scope of the character path.
+        scope: CharacterPathScope,
+    },
+
+    /// Select Character Spacing [`SHS`].
+    SelectCharacterSpacing(CharacterSpacing),
+
+    /// Select Editing Extent [`SEE`].
+    SelectEditingExtent(EditingExtend),
+
+    /// Select Graphic Rendition [`SGR`].
+    SelectGraphicRendition(Vec<GraphicRendition>),
+
+    /// Select Implicit Movement Direction [`SIMD`].
+    SelectImplicitMovementDirection(MovementDirection),
+
+    /// Select Line Spacing [`SVS`].
+    SelectLineSpacing(LineSpacing),
+
+    /// Select Presentation Directions [`SPD`].
+    SelectPresentationDirections {
+        /// The presentation direction.
+        direction: PresentationDirection,
+        /// The scope of the presentation direction.
+        scope: PresentationDirectionScope,
+    },
+
+    /// Select Print Quality and Rapidity [`SPQR`].
+    SelectPrintQualityAndRapidity(PrintQuality),
+
+    /// Select Size Unit [`SSU`].
+    SelectSizeUnit(SizeUnit),
+
+    /// Selective Tabulation [`STAB`].
+    SelectiveTabulation(u32),
+
+    /// Set Additional Character Separation [`SACS`].
+    SetAdditionalCharacterSeparation(u32),
+
+    /// Set Character Spacing [`SCS`].
+    SetCharacterSpacing(u32),
+
+    /// Set Line Home [`SLH`].
+    SetLineHome(u32),
+
+    /// Set Line Limit [`SLL`].
+    SetLineLimit(u32),
+
+    /// Set Line Spacing [`SLS`].
+    SetLineSpacing(u32),
+
+    /// Set Mode [`SM`].
+    SetMode(Vec<Mode>),
+
+    /// Set DEC Private Mode, see [`PrivateMode::set`].
+    SetPrivateMode(Vec<PrivateMode>),
+
+    /// Set Page Home [`SPH`].
+    SetPageHome(u32),
+
+    /// Set Page Limit [`SPL`].
+    SetPageLimit(u32),
+
+    /// Set Reduced Character Separation [`SRCS`].
+    SetReducedCharacterSeparation(u32),
+
+    /// Set Space Width [`SSW`].
+    SetSpaceWidth(u32),
+
+    /// Sheet Eject And Feed [`SEF`].
+    SheetEjectAndFeed {
+        /// The paper bin to load a new sheet from.
+        load: Load,
+        /// The stacker to eject the sheet into.
+        stack: Stack,
+    },
+
+    /// Spacing Increment [`SPI`].
+    SpacingIncrement {
+        /// The line spacing.
+        line: u32,
+        /// The character spacing.
+        character: u32,
+    },
+
+    /// Start Directed String [`SDS`].
+    StartDirectedString(StringDirection),
+
+    /// Start Reversed String [`SRS`].
+    StartReversedString(ReversedString),
+
+    /// Tabulation Aligned Centred [`TAC`].
+    TabulationAlignedCentred(u32),
+
+    /// Tabulation Aligned Leading Edge [`TALE`].
+    TabulationAlignedLeadingEdge(u32),
+
+    /// Tabulation Aligned Trailing Edge [`TATE`].
+    TabulationAlignedTrailingEdge(u32),
+
+    /// Tabulation Centred On Character [`TCC`].
+    TabulationCentredOnCharacter {
+        /// The position of the tabulation stop.
+        position: u32,
+        /// The code table position of the target character.
+        character: u32,
+    },
+
+    /// Tabulation Clear [`TBC`].
+    TabulationClear(ClearTabulation),
+
+    /// Tabulation Stop Remove [`TSR`].
+    TabulationStopRemove(u32),
+
+    /// Thin Space Specification [`TSS`].
+    ThinSpaceSpecification(u32),
+}
+
+/// Possible errors when decoding a [`ControlFunction`] into a [`Sequence`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InvalidSequence {
+    /// The control function is not one of the standardized control sequences. This is the case for all other types
+    /// of control functions, for control sequences that are declared as private use, and for control sequences with a
+    /// private parameter indicator.
+    NotStandardized,
+
+    /// The parameter at the given index has no default value, but is omitted.
+    MissingParameter(usize),
+
+    /// The parameter at the given index has a value that is not a valid selective parameter value.
+    InvalidSelection {
+        /// The index of the parameter.
+        index: usize,
+        /// The invalid value.
+        value: u32,
+    },
+
+    /// The parameter at the given index has sub-parameters, although its value does not take any.
+    UnexpectedSubParameter(usize),
+}
+
+impl fmt::Display for InvalidSequence {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InvalidSequence::NotStandardized => {
+                write!(
+                    formatter,
+                    "Control function is not a standardized control sequence"
+                )
+            }
+            InvalidSequence::MissingParameter(index) => {
+                write!(formatter, "Parameter {} is required, but omitted", index)
+            }
+            InvalidSequence::InvalidSelection { index, value } => write!(
+                formatter,
+                "Parameter {} has the invalid selective value {}",
+                index, value
+            ),
+            InvalidSequence::UnexpectedSubParameter(index) => {
+                write!(
+                    formatter,
+                    "Parameter {} has unexpected sub-parameters",
+                    index
+                )
+            }
+        }
+    }
+}
+
+impl Error for InvalidSequence {}
+
+/// Decodes the parameters of a control function.
+struct Decoder<'c, 'a> {
+    control_function: &'c ControlFunction<'a>,
+}
+
+impl Decoder<'_, '_> {
+    /// Returns the numeric value of the parameter at `index`, or `default` if it is omitted.
+    fn numeric(&self, index: usize, default: u32) -> u32 {
+        self.control_function.numeric_parameter(index, default)
+    }
+
+    /// Returns the numeric value of the parameter at `index`, which has no default value.
+    fn required(&self, index: usize) -> Result<u32, InvalidSequence> {
+        self.control_function
+            .parameters()
+            .get(index)
+            .and_then(Parameter::value)
+            .ok_or(InvalidSequence::MissingParameter(index))
+    }
+
+    /// Returns the selective parameter value of the parameter at `index`, with the default value `0`.
+    fn selective<T: TryFrom<u32>>(&self, index: usize) -> Result<T, InvalidSequence> {
+        select(index, self.numeric(index, 0))
+    }
+
+    /// Returns the selective parameter value of the parameter at `index`, which has no default value.
+    fn required_selective<T: TryFrom<u32>>(&self, index: usize) -> Result<T, InvalidSequence> {
+        select(index, self.required(index)?)
+    }
+
+    /// Returns the selective parameter values of all parameters, which have no default value.
+    fn variadic_required_selective<T: TryFrom<u32>>(&self) -> Result<Vec<T>, InvalidSequence> {
+        (0..self.control_function.parameters().len())
+            .map(|index| self.required_selective(index))
+            .collect()
+    }
+
+    /// Returns the graphic renditions of all parameters, with the default value `0`.
+    ///
+    /// A color of the extended color palettes is selected either by the parameters following its selector, or by the
+    /// sub-parameters of its selector.
+    fn graphic_renditions(&self) -> Result<Vec<GraphicRendition>, InvalidSequence> {
+        let parameters = self.control_function.parameters();
+        let mut graphic_renditions = Vec::with_capacity(parameters.len());
+
+        let mut index = 0;
+        while index < parameters.len() {
+            let value = parameters[index].value().unwrap_or(0);
+            let sub_parameters = parameters[index].sub_parameters();
+
+            let graphic_rendition = match value {
+                38 | 48 | 58 => {
+                    let color = if sub_parameters.is_empty() {
+                        let values = parameters[index + 1..]
+                            .iter()
+                            .map(Parameter::value)
+                            .collect::<Vec<_>>();
+                        let (color, length) =
+                            Color::decode(&values, false, |position| index + 1 + position)?;
+                        // the values selecting the color are separate parameters, skip them.
+                        index += length;
+                        color
+                    } else {
+                        Color::decode(sub_parameters, true, |_| index)?.0
+                    };
+                    match value {
+                        38 => GraphicRendition::Foreground(color),
+                        48 => GraphicRendition::Background(color),
+                        _ => GraphicRendition::UnderlineColor(color),
+                    }
+                }
+                4 if !sub_parameters.is_empty() => {
+                    let style = sub_parameters[0].unwrap_or(0);
+                    GraphicRendition::UnderlineStyle(select(index, style)?)
+                }
+                _ if !sub_parameters.is_empty() => {
+                    return Err(InvalidSequence::UnexpectedSubParameter(index))
+                }
+                _ => select(index, value)?,
+            };
+
+            graphic_renditions.push(graphic_rendition);
+            index += 1;
+        }
+
+        if graphic_renditions.is_empty() {
+            graphic_renditions.push(GraphicRendition::default());
+        }
+        Ok(graphic_renditions)
+    }
+}
+
+fn select<T: TryFrom<u32>>(index: usize, value: u32) -> Result<T, InvalidSequence> {
+    T::try_from(value).map_err(|_| InvalidSequence::InvalidSelection { index, value })
+}
+
+impl<'a> TryFrom<&ControlFunction<'a>> for Sequence {
+    type Error = InvalidSequence;
+
+    fn try_from(control_function: &ControlFunction<'a>) -> Result<Self, Self::Error> {
+        if control_function.kind() != ControlFunctionType::ControlSequence {
+            return Err(InvalidSequence::NotStandardized);
+        }
+
+        let parameters = Decoder { control_function };
+        if let Some(private_parameter) = control_function.private_parameter() {
+            return match (private_parameter, &*control_function.value) {
+                (PrivateParameter::QuestionMark, function::SM) => Ok(Sequence::SetPrivateMode(
+                    parameters.variadic_required_selective()?,
+                )),
+                (PrivateParameter::QuestionMark, function::RM) => Ok(Sequence::ResetPrivateMode(
+                    parameters.variadic_required_selective()?,
+                )),
+                _ => Err(InvalidSequence::NotStandardized),
+            };
+        }
+
+        Ok(match &*control_function.value {
+            // control sequences without intermediate bytes
+            function::ICH => Sequence::InsertCharacter(parameters.numeric(0, 1)),
+            function::CUU => Sequence::CursorUp(parameters.numeric(0, 1)),
+            function::CUD => Sequence::CursorDown(parameters.numeric(0, 1)),
+            function::CUF => Sequence::CursorRight(parameters.numeric(0, 1)),
+            function::CUB => Sequence::CursorLeft(parameters.numeric(0, 1)),
+            function::CNL => Sequence::CursorNextLine(parameters.numeric(0, 1)),
+            function::CPL => Sequence::CursorPrecedingLine(parameters.numeric(0, 1)),
+            function::CHA => Sequence::CursorCharacterAbsolute(parameters.numeric(0, 1)),
+            function::CUP => Sequence::CursorPosition {
+                line: parameters.numeric(0, 1),
+                column: parameters.numeric(1, 1),
+            },
+            function::CHT => Sequence::CursorForwardTabulation(parameters.numeric(0, 1)),
+            function::ED => Sequence::EraseInPage(parameters.selective(0)?),
+            function::EL => Sequence::EraseInLine(parameters.selective(0)?),
+            function::IL => Sequence::InsertLine(parameters.numeric(0, 1)),
+            function::DL => Sequence::DeleteLine(parameters.numeric(0, 1)),
+            function::EF => Sequence::EraseInField(parameters.selective(0)?),
+            function::EA => Sequence::EraseInArea(parameters.selective(0)?),
+            function::DCH => Sequence::DeleteCharacter(parameters.numeric(0, 1)),
+            function::SEE => Sequence::SelectEditingExtent(parameters.selective(0)?),
+            function::CPR => Sequence::ActivePositionReport {
+                line: parameters.numeric(0, 1),
+                column: parameters.numeric(1, 1),
+            },
+            function::SU => Sequence::ScrollUp(parameters.numeric(0, 1)),
+            function::SD => Sequence::ScrollDown(parameters.numeric(0, 1)),
+            function::NP => Sequence::NextPage(parameters.numeric(0, 1)),
+            function::PP => Sequence::PrecedingPage(parameters.numeric(0, 1)),
+            function::CTC => Sequence::CursorTabulationControl(parameters.selective(0)?),
+            function::ECH => Sequence::EraseCharacter(parameters.numeric(0, 1)),
+            function::CVT => Sequence::CursorLineTabulation(parameters.numeric(0, 1)),
+            function::CBT => Sequence::CursorBackwardTabulation(parameters.numeric(0, 1)),
+            function::SRS => Sequence::StartReversedString(parameters.selective(0)?),
+            function::PTX => Sequence::ParallelTexts(parameters.selective(0)?),
+            function::SDS => Sequence::StartDirectedString(parameters.selective(0)?),
+            function::SIMD => Sequence::SelectImplicitMovementDirection(parameters.selective(0)?),
+            function::HPA => Sequence::CharacterPositionAbsolute(parameters.numeric(0, 1)),
+            function::HPR => Sequence::CharacterPositionForward(parameters.numeric(0, 1)),
+            function::REP => Sequence::Repeat(parameters.numeric(0, 1)),
+            function::DA => Sequence::DeviceAttributes(parameters.selective(0)?),
+            function::VPA => Sequence::LinePositionAbsolute(parameters.numeric(0, 1)),
+            function::VPR => Sequence::LinePositionForward(parameters.numeric(0, 1)),
+            function::HVP => Sequence::CharacterAndLinePosition {
+                line: parameters.numeric(0, 1),
+                column: parameters.numeric(1, 1),
+            },
+            function::TBC => Sequence::TabulationClear(parameters.selective(0)?),
+            function::SM => Sequence::SetMode(parameters.variadic_required_selective()?),
+            function::MC => Sequence::MediaCopy(parameters.selective(0)?),
+            function::HPB => Sequence::CharacterPositionBackward(parameters.numeric(0, 1)),
+            function::VPB => Sequence::LinePositionBackward(parameters.numeric(0, 1)),
+            function::RM => Sequence::ResetMode(parameters.variadic_required_selective()?),
+            function::SGR => Sequence::SelectGraphicRendition(parameters.graphic_renditions()?),
+            function::DSR => Sequence::DeviceStatusReport(parameters.selective(0)?),
+            function::DAQ => Sequence::DefineAreaQualification(parameters.selective(0)?),
+
+            // control sequences with intermediate byte 02/00
+            function::SL => Sequence::ScrollLeft(parameters.numeric(0, 1)),
+            function::SR => Sequence::ScrollRight(parameters.numeric(0, 1)),
+            function::GSM => Sequence::GraphicSizeModification {
+                height: parameters.numeric(0, 100),
+                width: parameters.numeric(1, 100),
+            },
+            function::GSS => Sequence::GraphicSizeSelection(parameters.required(0)?),
+            function::FNT => Sequence::FontSelection {
+                font: parameters.selective(0)?,
+                identification: parameters.numeric(1, 0),
+            },
+            function::TSS => Sequence::ThinSpaceSpecification(parameters.required(0)?),
+            function::JFY => Sequence::Justify(parameters.selective(0)?),
+            function::SPI => Sequence::SpacingIncrement {
+                line: parameters.required(0)?,
+                character: parameters.required(1)?,
+            },
+            function::QUAD => Sequence::Quad(parameters.selective(0)?),
+            function::SSU => Sequence::SelectSizeUnit(parameters.selective(0)?),
+            function::PFS => Sequence::PageFormatSelection(parameters.selective(0)?),
+            function::SHS => Sequence::SelectCharacterSpacing(parameters.selective(0)?),
+            function::SVS => Sequence::SelectLineSpacing(parameters.selective(0)?),
+            function::IGS => Sequence::IdentifyGraphicSubrepertoire(parameters.required(0)?),
+            function::IDCS => {
+                Sequence::IdentifyDeviceControlString(parameters.required_selective(0)?)
+            }
+            function::PPA => Sequence::PagePositionAbsolute(parameters.numeric(0, 1)),
+            function::PPR => Sequence::PagePositionForward(parameters.numeric(0, 1)),
+            function::PPB => Sequence::PagePositionBackward(parameters.numeric(0, 1)),
+            function::SPD => Sequence::SelectPresentationDirections {
+                direction: parameters.selective(0)?,
+                scope: parameters.selective(1)?,
+            },
+            function::DTA => Sequence::DimensionTextArea {
+                perpendicular: parameters.required(0)?,
+                parallel: parameters.required(1)?,
+            },
+            function::SLH => Sequence::SetLineHome(parameters.required(0)?),
+            function::SLL => Sequence::SetLineLimit(parameters.required(0)?),
+            function::FNK => Sequence::FunctionKey(parameters.required(0)?),
+            function::SPQR => Sequence::SelectPrintQualityAndRapidity(parameters.selective(0)?),
+            function::SEF => Sequence::SheetEjectAndFeed {
+                load: parameters.selective(0)?,
+                stack: parameters.selective(1)?,
+            },
+            function::PEC => Sequence::PresentationExpandOrContract(parameters.selective(0)?),
+            function::SSW => Sequence::SetSpaceWidth(parameters.required(0)?),
+            function::SACS => Sequence::SetAdditionalCharacterSeparation(parameters.numeric(0, 0)),
+            function::SAPV => {
+                Sequence::SelectAlternativePresentationVariants(parameters.selective(0)?)
+            }
+            function::STAB => Sequence::SelectiveTabulation(parameters.required(0)?),
+            function::GCC => Sequence::GraphicCharacterCombination(parameters.selective(0)?),
+            function::TATE => Sequence::TabulationAlignedTrailingEdge(parameters.required(0)?),
+            function::TALE => Sequence::TabulationAlignedLeadingEdge(parameters.required(0)?),
+            function::TAC => Sequence::TabulationAlignedCentred(parameters.required(0)?),
+            function::TCC => Sequence::TabulationCentredOnCharacter {
+                position: parameters.required(0)?,
+                character: parameters.numeric(1, 32),
+            },
+            function::TSR => Sequence::TabulationStopRemove(parameters.required(0)?),
+            function::SCO => Sequence::SelectCharacterOrientation(parameters.selective(0)?),
+            function::SRCS => Sequence::SetReducedCharacterSeparation(parameters.numeric(0, 0)),
+            function::SCS => Sequence::SetCharacterSpacing(parameters.required(0)?),
+            function::SLS => Sequence::SetLineSpacing(parameters.required(0)?),
+            function::SPH => Sequence::SetPageHome(parameters.required(0)?),
+            function::SPL => Sequence::SetPageLimit(parameters.required(0)?),
+            function::SCP => Sequence::SelectCharacterPath {
+                path: parameters.required_selective(0)?,
+                scope: parameters.required_selective(1)?,
+            },
+
+            // all other control sequences are not standardized
+            _ => return Err(InvalidSequence::NotStandardized),
+        })
+    }
 }
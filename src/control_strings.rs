@@ -32,8 +32,46 @@
 //! let halt_command = operating_system_command("HALT");
 //! println!("{}", halt_command);
 //! ```
+//!
+//! ## Terminators
+//!
+//! ECMA-48 only specifies the STRING TERMINATOR ([`ST`]) to terminate control strings. Most terminals also accept
+//! BELL ([`BEL`]) as terminator of an operating system command, and many applications send it instead of [`ST`].
+//! Use [`operating_system_command_with_terminator`] to choose the [`Terminator`] of an operating system command.
+//!
+//! ```
+//! use ansi_control_codes::control_strings::{operating_system_command_with_terminator, Terminator};
+//! let set_title = operating_system_command_with_terminator("0;Window Title", Terminator::Bell);
+//! assert_eq!(set_title, "\x1b]0;Window Title\x07");
+//! ```
+
+use crate::{
+    c0::BEL,
+    c1::{APC, DCS, OSC, PM, SOS, ST},
+    ControlFunction,
+};
 
-use crate::c1::{APC, DCS, OSC, PM, SOS, ST};
+/// The terminating delimiter of a control string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum Terminator {
+    /// The control string is terminated by STRING TERMINATOR ([`ST`]), as specified by ECMA-48.
+    #[default]
+    StringTerminator,
+
+    /// The control string is terminated by BELL ([`BEL`]).
+    ///
+    /// This is not specified by ECMA-48, but commonly used to terminate operating system commands.
+    Bell,
+}
+
+impl From<Terminator> for ControlFunction<'static> {
+    fn from(terminator: Terminator) -> Self {
+        match terminator {
+            Terminator::StringTerminator => ST,
+            Terminator::Bell => BEL,
+        }
+    }
+}
 
 /// Creates a new Application Program Command.
 ///
@@ -62,7 +100,25 @@ pub fn device_control_string(control_string: &str) -> String {
 ///
 /// The interpretation of the command string depends on the relevant operating system.
 pub fn operating_system_command(system_command: &str) -> String {
-    format!("{}{}{}", OSC, system_command, ST)
+    operating_system_command_with_terminator(system_command, Terminator::StringTerminator)
+}
+
+/// Creates a new Operating System Command with the given terminator.
+///
+/// The given system command will be prefixed with [`OSC`] and suffixed with the control function of the given
+/// `terminator`, either [`ST`] or [`BEL`].
+///
+/// The interpretation of the command string depends on the relevant operating system.
+pub fn operating_system_command_with_terminator(
+    system_command: &str,
+    terminator: Terminator,
+) -> String {
+    format!(
+        "{}{}{}",
+        OSC,
+        system_command,
+        ControlFunction::from(terminator)
+    )
 }
 
 /// Creates a new Privacy Message.
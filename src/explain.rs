@@ -38,7 +38,11 @@
 
 use std::{convert::Infallible, str::FromStr};
 
-use crate::{control_sequences::*, modes::Mode, ControlFunction, ControlFunctionType};
+use crate::{
+    control_sequences::*,
+    modes::{Mode, PrivateMode},
+    ControlFunction, ControlFunctionType, Parameter, PrivateParameter,
+};
 
 macro_rules! param {
     ($self:ident, $index:literal, $default:literal) => {
@@ -49,21 +53,19 @@ macro_rules! param {
     };
 }
 
+/// Explains a selective parameter value. If the value is invalid, the enclosing function returns the explanation of
+/// the error instead.
 macro_rules! explain_selection {
     ($selection:ident, $self:ident, $index:literal) => {
-        $selection::from_str(
-            $self
-                .parameters
-                .get($index)
-                .map(&String::as_ref)
-                .unwrap_or(""),
-        )
-        .expect("Reached infallible code.")
-        .explain()
+        match explain_selection::<$selection>($self, $index) {
+            Ok(explanation) => explanation,
+            Err(error) => return format!("{}.", error),
+        }
     };
 }
 
 #[derive(Debug)]
+#[allow(clippy::upper_case_acronyms)]
 enum Function {
     // C0
     ACK,
@@ -155,6 +157,8 @@ enum Function {
     DA,
     DAQ,
     DCH,
+    DECRST,
+    DECSET,
     DL,
     DSR,
     DTA,
@@ -335,6 +339,16 @@ fn function(control_function: &ControlFunction<'_>) -> Function {
             }
         }
         ControlFunctionType::ControlSequence => {
+            // control sequences with private parameters are not standardized, except for the common extensions
+            // that set and reset DEC private modes
+            if let Some(private_parameter) = control_function.private_parameter {
+                return match (private_parameter, control_function.value.as_ref()) {
+                    (PrivateParameter::QuestionMark, "h") => Function::DECSET,
+                    (PrivateParameter::QuestionMark, "l") => Function::DECRST,
+                    _ => Function::PRIVATE,
+                };
+            }
+
             let bytes = control_function.value.as_bytes();
             if bytes.len() == 1 {
                 // control sequence with no intermediate byte
@@ -387,14 +401,12 @@ fn function(control_function: &ControlFunction<'_>) -> Function {
                     109 => Function::SGR,
                     110 => Function::DSR,
                     111 => Function::DAQ,
-                    112..=127 => Function::PRIVATE,
-                    _ => {
-                        unreachable!("No valid control sequence exist outside of above range")
-                    }
+                    // all other control sequences are not standardized
+                    _ => Function::PRIVATE,
                 };
             }
-            if bytes.len() == 2 {
-                // control sequence with intermediate byte
+            if bytes.len() == 2 && bytes[0] == 32 {
+                // control sequence with intermediate byte 02/00
                 let byte = bytes[1];
                 return match byte {
                     64 => Function::SL,
@@ -440,13 +452,12 @@ fn function(control_function: &ControlFunction<'_>) -> Function {
                     105 => Function::SPH,
                     106 => Function::SPL,
                     107 => Function::SCP,
-                    112..=127 => Function::PRIVATE,
-                    _ => {
-                        unreachable!("No valid control sequence exist outside of above range")
-                    }
+                    // all other control sequences are not standardized
+                    _ => Function::PRIVATE,
                 };
             }
-            unreachable!("No valid control sequence exist outside of above range")
+            // control sequences with other or more intermediate bytes are not standardized
+            Function::PRIVATE
         }
     }
 }
@@ -466,10 +477,10 @@ fn ordinal_indicator(numeric_value: String) -> String {
         .unwrap_or_else(|_| numeric_value)
 }
 
-fn get_param(parameters: &Vec<String>, index: usize, default_value: u64) -> String {
+fn get_param(parameters: &[Parameter], index: usize, default_value: u64) -> String {
     parameters
         .get(index)
-        .map(|value| value.to_owned())
+        .map(Parameter::to_string)
         .unwrap_or_else(|| format!("{default_value}"))
 }
 
@@ -477,12 +488,127 @@ trait ExplainSelection {
     fn explain(&self) -> String;
 }
 
+/// Explains the selective parameter value of the parameter at `index` of `control_function`.
+///
+/// A parameter that is omitted falls back to the default value `0`. Values that do not select anything, and omitted
+/// parameters without a default value, are rejected.
+fn explain_selection<T: ExplainSelection + TryFrom<u32>>(
+    control_function: &ControlFunction,
+    index: usize,
+) -> Result<String, InvalidSequence> {
+    let selection = match control_function
+        .parameters()
+        .get(index)
+        .and_then(Parameter::value)
+    {
+        Some(value) => {
+            T::try_from(value).map_err(|_| InvalidSequence::InvalidSelection { index, value })
+        }
+        None => T::try_from(0).map_err(|_| InvalidSequence::MissingParameter(index)),
+    };
+
+    selection.map(|selection| selection.explain())
+}
+
+/// Explains the graphic renditions that are selected by the parameters of the control function SGR.
+fn explain_graphic_renditions(control_function: &ControlFunction) -> String {
+    match Sequence::try_from(control_function) {
+        Ok(Sequence::SelectGraphicRendition(graphic_renditions)) => graphic_renditions
+            .iter()
+            .map(GraphicRendition::explain)
+            .collect::<Vec<_>>()
+            .join(" "),
+        Ok(_) => unreachable!("SGR is always decoded as SelectGraphicRendition."),
+        Err(error) => format!("{}.", error),
+    }
+}
+
+/// Explains a color of the extended color palettes.
+fn explain_color(color: &Color) -> String {
+    match color {
+        Color::Indexed(index) => format!("color {} of the palette of 256 colors", index),
+        Color::Rgb(red, green, blue) => {
+            format!(
+                "direct color with red {}, green {} and blue {}",
+                red, green, blue
+            )
+        }
+    }
+}
+
 trait ExplainMode {
     fn name(&self) -> String;
     fn explain_reset(&self) -> String;
     fn explain_set(&self) -> String;
 }
 
+/// Returns the modes that are selected by the parameters of the control functions SM and RM, or the values that do
+/// not select a known mode. A missing value is `None`.
+fn modes<'b>(
+    control_function: &'b ControlFunction,
+) -> impl Iterator<Item = Result<Mode, Option<u32>>> + 'b {
+    control_function.parameters.iter().map(|parameter| {
+        let value = parameter.value().ok_or(None)?;
+        Mode::try_from(value).map_err(|_| Some(value))
+    })
+}
+
+/// Returns the DEC private modes that are selected by the parameters of the control functions DECSET and DECRST, or
+/// the values that do not select a known private mode. A missing value is `None`.
+fn private_modes<'b>(
+    control_function: &'b ControlFunction,
+) -> impl Iterator<Item = Result<PrivateMode, Option<u32>>> + 'b {
+    control_function.parameters.iter().map(|parameter| {
+        let value = parameter.value().ok_or(None)?;
+        PrivateMode::try_from(value).map_err(|_| Some(value))
+    })
+}
+
+/// Returns the names of the `modes`, separated by commas. Values that do not select a known mode are labeled as
+/// `unknown` modes.
+fn mode_names<T: ExplainMode>(
+    modes: impl Iterator<Item = Result<T, Option<u32>>>,
+    unknown: &str,
+) -> String {
+    let names = modes
+        .map(|mode| match mode {
+            Ok(mode) => mode.name(),
+            Err(Some(value)) => format!("{} {}", unknown, value),
+            Err(None) => String::from("no mode given"),
+        })
+        .collect::<Vec<_>>();
+
+    if names.is_empty() {
+        String::from("no mode given")
+    } else {
+        names.join(", ")
+    }
+}
+
+/// Explains setting (or resetting, if `set` is false) each of the `modes`. Values that do not select a known mode are
+/// labeled as `unknown` modes.
+fn explain_modes<T: ExplainMode>(
+    modes: impl Iterator<Item = Result<T, Option<u32>>>,
+    unknown: &str,
+    set: bool,
+) -> String {
+    let action = if set { "set" } else { "reset" };
+    let explanations = modes
+        .map(|mode| match mode {
+            Ok(mode) if set => mode.explain_set(),
+            Ok(mode) => mode.explain_reset(),
+            Err(Some(value)) => format!("The {} {} is {}.", unknown, value, action),
+            Err(None) => String::from("A missing mode is ignored."),
+        })
+        .collect::<Vec<_>>();
+
+    if explanations.is_empty() {
+        String::from("No mode is given.")
+    } else {
+        explanations.join(" ")
+    }
+}
+
 /// Explanation of an ansi-control-code.
 pub trait Explain {
     /// Returns the short name (abbreviation) of this control function, e.g. `CR`, `LF`.
@@ -505,7 +631,7 @@ pub trait Explain {
 
 impl Explain for ControlFunction<'_> {
     fn short_name(&self) -> Option<&'static str> {
-        match function(&self) {
+        match function(self) {
             Function::ACK => Some("ACK"),
             Function::BEL => Some("BEL"),
             Function::BS => Some("BS"),
@@ -592,6 +718,8 @@ impl Explain for ControlFunction<'_> {
             Function::DA => Some("DA"),
             Function::DAQ => Some("DAQ"),
             Function::DCH => Some("DCH"),
+            Function::DECRST => Some("DECRST"),
+            Function::DECSET => Some("DECSET"),
             Function::DL => Some("DL"),
             Function::DSR => Some("DSR"),
             Function::DTA => Some("DTA"),
@@ -671,7 +799,7 @@ impl Explain for ControlFunction<'_> {
     }
 
     fn long_name(&self) -> &'static str {
-        match function(&self) {
+        match function(self) {
             Function::ACK => "Acknowledge",
             Function::BEL => "Bell",
             Function::BS => "Backspace",
@@ -758,6 +886,8 @@ impl Explain for ControlFunction<'_> {
             Function::DA => "Device Attributes",
             Function::DAQ => "Define Area Qualification",
             Function::DCH => "Delete Character",
+            Function::DECRST => "DEC Private Mode Reset",
+            Function::DECSET => "DEC Private Mode Set",
             Function::DL => "Delete Line",
             Function::DSR => "Device Status Report",
             Function::DTA => "Dimension Text Area",
@@ -837,7 +967,7 @@ impl Explain for ControlFunction<'_> {
     }
 
     fn short_description(&self) -> String {
-        match function(&self) {
+        match function(self) {
             Function::ACK => {
                 String::from("Transmitted by a receiver as an affirmative response to the sender.")
             }
@@ -1058,6 +1188,14 @@ impl Explain for ControlFunction<'_> {
                 "Delete {} characters, starting from the active position to the left.",
                 param!(self, 0, 1)
             ),
+            Function::DECRST => format!(
+                "Reset the following DEC Private Modes: {}.",
+                mode_names(private_modes(self), "Unknown Private Mode")
+            ),
+            Function::DECSET => format!(
+                "Set the following DEC Private Modes: {}.",
+                mode_names(private_modes(self), "Unknown Private Mode")
+            ),
             Function::DL => format!(
                 "Delete {} lines",
                 param!(self, 0, 1)
@@ -1177,14 +1315,8 @@ impl Explain for ControlFunction<'_> {
                 param!(self, 0, 1)
             ),
             Function::RM => format!(
-                "Reset the following Modes: {}",
-                self.parameters.iter().map(|value| {
-                    value.parse::<Mode>().expect("Expect only valid Modes").name()
-                }).fold(String::new(), |mut modes, mode| {
-                    modes.push_str(", ");
-                    modes.push_str(&mode);
-                    modes
-                })
+                "Reset the following Modes: {}.",
+                mode_names(modes(self), "Unknown Mode")
             ),
             Function::SACS => format!(
                 "Enlarge inter-character escapement by {} units.",
@@ -1224,14 +1356,8 @@ impl Explain for ControlFunction<'_> {
                 explain_selection!(Stack, self, 1)
             ),
             Function::SGR => format!(
-                "Change the representation of following text. {}.",
-                self.parameters.iter().map(|value| {
-                    value.parse::<GraphicRendition>().expect("Expect only valid Graphic Renditions").explain()
-                }).fold(String::new(), |mut renditions, rendition| {
-                    renditions.push_str(", ");
-                    renditions.push_str(&rendition);
-                    renditions
-                })
+                "Change the representation of following text. {}",
+                explain_graphic_renditions(self)
             ),
             Function::SHS => explain_selection!(CharacterSpacing, self, 0),
             Function::SIMD => explain_selection!(MovementDirection, self, 0),
@@ -1252,14 +1378,8 @@ impl Explain for ControlFunction<'_> {
                 param!(self, 0, 0)
             ),
             Function::SM => format!(
-                "Set the following Modes: {}",
-                self.parameters.iter().map(|value| {
-                    value.parse::<Mode>().expect("Expect only valid Modes").name()
-                }).fold(String::new(), |mut modes, mode| {
-                    modes.push_str(", ");
-                    modes.push_str(&mode);
-                    modes
-                })
+                "Set the following Modes: {}.",
+                mode_names(modes(self), "Unknown Mode")
             ),
             Function::SPD => format!(
                 "In {}, set the presentation direction to {}.",
@@ -1380,7 +1500,7 @@ impl Explain for ControlFunction<'_> {
     }
 
     fn long_description(&self) -> String {
-        match function(&self) {
+        match function(self) {
             Function::BEL => String::from(
                 "Calls for the attention of the user by controlling an alarm or attention device.",
             ),
@@ -1907,6 +2027,8 @@ impl Explain for ControlFunction<'_> {
                 param!(self, 0, 1),
                 param!(self, 0, 1)
             ),
+            Function::DECRST => explain_modes(private_modes(self), "unknown Private Mode", false),
+            Function::DECSET => explain_modes(private_modes(self), "unknown Private Mode", true),
             Function::DL => format!(
                 concat!(
                     "If the 'Device Component Select Mode' (DSCM) is set to 'Presentation', it causes the contents of ",
@@ -2211,15 +2333,7 @@ impl Explain for ControlFunction<'_> {
                 ),
                 param!(self, 0, 1)
             ),
-            Function::RM =>
-                self.parameters.iter().map(|value| {
-                    value.parse::<Mode>().expect("Expect only valid Modes").explain_reset()
-                }).fold(String::new(), |mut modes, mode| {
-                    modes.push_str(", ");
-                    modes.push_str(&mode);
-                    modes
-                }
-            ),
+            Function::RM => explain_modes(modes(self), "unknown Mode", false),
             Function::SACS => format!(
                 concat!(
                     "Used to establish extra inter-character escapement for subsequent text. The established extra ",
@@ -2286,13 +2400,7 @@ impl Explain for ControlFunction<'_> {
                     "remain in effect until the next occurrence, depending on the setting of the 'Graphic Rendition ",
                     "Combination Mode' (GRCM).\n\n{}"
                 ),
-                self.parameters.iter().map(|value| {
-                    value.parse::<GraphicRendition>().expect("Expect only valid Graphic Renditions").explain()
-                }).fold(String::new(), |mut renditions, rendition| {
-                    renditions.push_str(", ");
-                    renditions.push_str(&rendition);
-                    renditions
-                })
+                explain_graphic_renditions(self)
             ),
             Function::SHS => format!(
                 concat!(
@@ -2374,15 +2482,7 @@ impl Explain for ControlFunction<'_> {
                 ),
                 self.short_description()
             ),
-            Function::SM =>
-                self.parameters.iter().map(|value| {
-                    value.parse::<Mode>().expect("Expect only valid Modes").explain_set()
-                }).fold(String::new(), |mut modes, mode| {
-                    modes.push_str(", ");
-                    modes.push_str(&mode);
-                    modes
-                }
-            ),
+            Function::SM => explain_modes(modes(self), "unknown Mode", true),
             Function::SPH => format!(
                 concat!(
                     "If the 'Device Component Select Mode' (DCSM) is set to 'Presentation', this is used to establish ",
@@ -2611,22 +2711,6 @@ impl Explain for ControlFunction<'_> {
     }
 }
 
-impl FromStr for TabulationControl {
-    type Err = Infallible;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(match s {
-            "1" => Self::SetLineTabulationStop,
-            "2" => Self::ClearCharacterTabulationStop,
-            "3" => Self::ClearLineTabulationStop,
-            "4" => Self::ClearCharacterTabulationStopsInLine,
-            "5" => Self::ClearAllCharacterTabulationStops,
-            "6" => Self::ClearLineTabulationStop,
-            _ => Self::SetCharacterTabulationStop,
-        })
-    }
-}
-
 impl ExplainSelection for TabulationControl {
     fn explain(&self) -> String {
         match self {
@@ -2653,21 +2737,6 @@ impl ExplainSelection for TabulationControl {
     }
 }
 
-impl FromStr for DeviceAttributes {
-    type Err = Infallible;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(match s {
-            "0" => Self::Request,
-            value @ _ => Self::Identify(
-                value
-                    .parse::<u32>()
-                    .expect("Expected valid Device Attributes."),
-            ),
-        })
-    }
-}
-
 impl ExplainSelection for DeviceAttributes {
     fn explain(&self) -> String {
         match self {
@@ -2684,27 +2753,6 @@ impl ExplainSelection for DeviceAttributes {
     }
 }
 
-impl FromStr for AreaQualification {
-    type Err = Infallible;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(match s {
-            "1" => Self::ProtectedGuarded,
-            "2" => Self::GraphicCharacterInput,
-            "3" => Self::NumericInput,
-            "4" => Self::AlphabeticInput,
-            "5" => Self::InputAlignedRight,
-            "6" => Self::FillZeros,
-            "7" => Self::SetCharacterTabulationStop,
-            "8" => Self::ProtectedUnguarded,
-            "9" => Self::FillSpaces,
-            "10" => Self::InputAlignedLeft,
-            "11" => Self::Reversed,
-            _ => Self::UnprotectedUnguarded,
-        })
-    }
-}
-
 impl ExplainSelection for AreaQualification {
     fn explain(&self) -> String {
         match self {
@@ -2730,22 +2778,6 @@ impl ExplainSelection for AreaQualification {
     }
 }
 
-impl FromStr for DeviceStatusReport {
-    type Err = Infallible;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(match s {
-            "1" => Self::BusyRepeat,
-            "2" => Self::BusyLater,
-            "3" => Self::MalfunctionRepeat,
-            "4" => Self::MalfunctionLater,
-            "5" => Self::RequestDeviceStatusReport,
-            "6" => Self::RequestActivePositionReport,
-            _ => Self::Ready,
-        })
-    }
-}
-
 impl ExplainSelection for DeviceStatusReport {
     fn explain(&self) -> String {
         match self {
@@ -2783,18 +2815,6 @@ impl ExplainSelection for DeviceStatusReport {
     }
 }
 
-impl FromStr for EraseArea {
-    type Err = Infallible;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(match s {
-            "1" => Self::BeginToActivePosition,
-            "2" => Self::BeginToEnd,
-            _ => Self::ActivePositionToEnd,
-        })
-    }
-}
-
 impl ExplainSelection for EraseArea {
     fn explain(&self) -> String {
         match self {
@@ -2814,18 +2834,6 @@ impl ExplainSelection for EraseArea {
     }
 }
 
-impl FromStr for ErasePage {
-    type Err = Infallible;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(match s {
-            "1" => Self::BeginToActivePosition,
-            "2" => Self::BeginToEnd,
-            _ => Self::ActivePositionToEnd,
-        })
-    }
-}
-
 impl ExplainSelection for ErasePage {
     fn explain(&self) -> String {
         match self {
@@ -2845,18 +2853,6 @@ impl ExplainSelection for ErasePage {
     }
 }
 
-impl FromStr for EraseField {
-    type Err = Infallible;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(match s {
-            "1" => Self::BeginToActivePosition,
-            "2" => Self::BeginToEnd,
-            _ => Self::ActivePositionToEnd,
-        })
-    }
-}
-
 impl ExplainSelection for EraseField {
     fn explain(&self) -> String {
         match self {
@@ -2876,18 +2872,6 @@ impl ExplainSelection for EraseField {
     }
 }
 
-impl FromStr for EraseLine {
-    type Err = Infallible;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(match s {
-            "1" => Self::BeginToActivePosition,
-            "2" => Self::BeginToEnd,
-            _ => Self::ActivePositionToEnd,
-        })
-    }
-}
-
 impl ExplainSelection for EraseLine {
     fn explain(&self) -> String {
         match self {
@@ -2907,25 +2891,6 @@ impl ExplainSelection for EraseLine {
     }
 }
 
-impl FromStr for Font {
-    type Err = Infallible;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(match s {
-            "1" => Self::Alternative1,
-            "2" => Self::Alternative2,
-            "3" => Self::Alternative3,
-            "4" => Self::Alternative4,
-            "5" => Self::Alternative5,
-            "6" => Self::Alternative6,
-            "7" => Self::Alternative7,
-            "8" => Self::Alternative8,
-            "9" => Self::Alternative9,
-            _ => Self::Primary,
-        })
-    }
-}
-
 impl ExplainSelection for Font {
     fn explain(&self) -> String {
         match self {
@@ -2943,18 +2908,6 @@ impl ExplainSelection for Font {
     }
 }
 
-impl FromStr for GraphicCharacterCombination {
-    type Err = Infallible;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(match s {
-            "1" => Self::StartOfCombination,
-            "2" => Self::EndOfCombination,
-            _ => Self::CombineTwo,
-        })
-    }
-}
-
 impl ExplainSelection for GraphicCharacterCombination {
     fn explain(&self) -> String {
         match self {
@@ -2974,22 +2927,6 @@ impl ExplainSelection for GraphicCharacterCombination {
     }
 }
 
-impl FromStr for IdentifyDeviceControlString {
-    type Err = Infallible;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(match s {
-            "0" => Self::Diagnostic,
-            "1" => Self::DynamicallyRedefinableCharacterSet,
-            value @ _ => Self::Private(
-                value
-                    .parse::<u32>()
-                    .expect("Expected valid Identify Device Control String."),
-            ),
-        })
-    }
-}
-
 impl ExplainSelection for IdentifyDeviceControlString {
     fn explain(&self) -> String {
         match self {
@@ -3012,24 +2949,6 @@ impl ExplainSelection for IdentifyDeviceControlString {
     }
 }
 
-impl FromStr for Justification {
-    type Err = Infallible;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(match s {
-            "1" => Self::WordFill,
-            "2" => Self::WordSpace,
-            "3" => Self::LetterSpace,
-            "4" => Self::Hyphenation,
-            "5" => Self::Left,
-            "6" => Self::Centre,
-            "7" => Self::Right,
-            "8" => Self::ItalianHyphenation,
-            _ => Self::None,
-        })
-    }
-}
-
 impl ExplainSelection for Justification {
     fn explain(&self) -> String {
         match self {
@@ -3052,23 +2971,6 @@ impl ExplainSelection for Justification {
     }
 }
 
-impl FromStr for MediaCopy {
-    type Err = Infallible;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(match s {
-            "1" => Self::BeginTransferFromPrimary,
-            "2" => Self::BeginTransferToSecondary,
-            "3" => Self::BeginTransferFromSecondary,
-            "4" => Self::StopRelayPrimary,
-            "5" => Self::StartRelayPrimary,
-            "6" => Self::StopRelaySecondary,
-            "7" => Self::StartRelaySecondary,
-            _ => Self::BeginTransferToPrimary,
-        })
-    }
-}
-
 impl ExplainSelection for MediaCopy {
     fn explain(&self) -> String {
         match self {
@@ -3094,18 +2996,6 @@ impl ExplainSelection for MediaCopy {
     }
 }
 
-impl FromStr for PresentationExpandContract {
-    type Err = Infallible;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(match s {
-            "1" => Self::Expanded,
-            "2" => Self::Condensed,
-            _ => Self::Normal,
-        })
-    }
-}
-
 impl ExplainSelection for PresentationExpandContract {
     fn explain(&self) -> String {
         match self {
@@ -3120,31 +3010,6 @@ impl ExplainSelection for PresentationExpandContract {
     }
 }
 
-impl FromStr for PageFormat {
-    type Err = Infallible;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(match s {
-            "1" => Self::WideBasicText,
-            "2" => Self::TallBasicA4,
-            "3" => Self::WideBasicA4,
-            "4" => Self::TallLetter,
-            "5" => Self::WideLetter,
-            "6" => Self::TallExtendedA4,
-            "7" => Self::WideExtendedA4,
-            "8" => Self::TallLegal,
-            "9" => Self::WideLegal,
-            "10" => Self::A4ShortLines,
-            "11" => Self::A4LongLines,
-            "12" => Self::B5ShortLines,
-            "13" => Self::B5LongLines,
-            "14" => Self::B4ShortLines,
-            "15" => Self::B4LongLines,
-            _ => Self::TallBasicText,
-        })
-    }
-}
-
 impl ExplainSelection for PageFormat {
     fn explain(&self) -> String {
         match self {
@@ -3168,21 +3033,6 @@ impl ExplainSelection for PageFormat {
     }
 }
 
-impl FromStr for ParallelText {
-    type Err = Infallible;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(match s {
-            "1" => Self::BeginPrincipal,
-            "2" => Self::BeginSupplementary,
-            "3" => Self::BeginJapanesePhonetic,
-            "4" => Self::BeginChinesePhonetic,
-            "5" => Self::EndPhonetic,
-            _ => Self::End,
-        })
-    }
-}
-
 impl ExplainSelection for ParallelText {
     fn explain(&self) -> String {
         match self {
@@ -3217,22 +3067,6 @@ impl ExplainSelection for ParallelText {
     }
 }
 
-impl FromStr for Alignment {
-    type Err = Infallible;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(match s {
-            "1" => Self::LineHomeLeader,
-            "2" => Self::Centre,
-            "3" => Self::CentreLeader,
-            "4" => Self::LineLimit,
-            "5" => Self::LineLimitLeader,
-            "6" => Self::Justify,
-            _ => Self::LineHome,
-        })
-    }
-}
-
 impl ExplainSelection for Alignment {
     fn explain(&self) -> String {
         match self {
@@ -3261,35 +3095,6 @@ impl ExplainSelection for Alignment {
     }
 }
 
-impl FromStr for Mode {
-    type Err = Infallible;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(match s {
-            "2" => Self::KeyboardActionMode,
-            "3" => Self::ControlPresentationMode,
-            "4" => Self::InsertionReplacementMode,
-            "5" => Self::StatusReportTransferMode,
-            "6" => Self::ErasureMode,
-            "7" => Self::LineEditingMode,
-            "8" => Self::BiDirectionalSupportMode,
-            "9" => Self::DeviceComponentSelectMode,
-            "10" => Self::CharacterEditingMode,
-            "11" => Self::PositioningUnitMode,
-            "12" => Self::SendReceiveMode,
-            "13" => Self::FormatEffectorActionMode,
-            "14" => Self::FormatEffectorTransferMode,
-            "15" => Self::MultipleAreaTransferMode,
-            "16" => Self::TransferTerminationMode,
-            "17" => Self::StatusReportTransferMode,
-            "18" => Self::TabulationStopMode,
-            "21" => Self::GraphicRenditionCombinationMode,
-            "22" => Self::ZeroDefaultMode,
-            _ => Self::GuardedAreaTransferMode,
-        })
-    }
-}
-
 impl ExplainMode for Mode {
     fn name(&self) -> String {
         match self {
@@ -3512,35 +3317,93 @@ impl ExplainMode for Mode {
     }
 }
 
-impl FromStr for PresentationVariant {
-    type Err = Infallible;
+impl ExplainMode for PrivateMode {
+    fn name(&self) -> String {
+        match self {
+            Self::OriginMode => String::from("Origin Mode"),
+            Self::AutoWrapMode => String::from("Auto Wrap Mode"),
+            Self::TextCursorEnableMode => String::from("Text Cursor Enable Mode"),
+            Self::NormalMouseTracking => String::from("Normal Mouse Tracking"),
+            Self::ButtonEventMouseTracking => String::from("Button Event Mouse Tracking"),
+            Self::AnyEventMouseTracking => String::from("Any Event Mouse Tracking"),
+            Self::FocusEventMode => String::from("Focus Event Mode"),
+            Self::SgrMouseMode => String::from("SGR Mouse Mode"),
+            Self::AlternateScreenBuffer => String::from("Alternate Screen Buffer"),
+            Self::BracketedPasteMode => String::from("Bracketed Paste Mode"),
+            Self::SynchronizedOutput => String::from("Synchronized Output"),
+        }
+    }
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(match s {
-            "1" => Self::LatinDecimals,
-            "2" => Self::ArabicDecimals,
-            "3" => Self::MirrorPairs,
-            "4" => Self::MirrorFormulae,
-            "5" => Self::Isolated,
-            "6" => Self::Initial,
-            "7" => Self::Medial,
-            "8" => Self::Final,
-            "9" => Self::DecimalFullStop,
-            "10" => Self::DecimalComma,
-            "11" => Self::VowelAboveOrBelow,
-            "12" => Self::VowelAfterPreceding,
-            "13" => Self::ContextualShapeArabicScriptWithLamAleph,
-            "14" => Self::ContextualShapeArabicScript,
-            "15" => Self::NoMirroring,
-            "16" => Self::NoVowels,
-            "17" => Self::SlantFollowsStringDirection,
-            "18" => Self::NoContextualShapeArabicScript,
-            "19" => Self::NoContextualShapeArabicScriptExceptDigits,
-            "20" => Self::DeviceDependentDecimalDigits,
-            "21" => Self::PersistCharacterForm,
-            "22" => Self::DesistCharacterForm,
-            _ => Self::Default,
-        })
+    fn explain_reset(&self) -> String {
+        match self {
+            Self::OriginMode => String::from(
+                "Cursor positions are relative to the upper left corner of the screen."
+            ),
+            Self::AutoWrapMode => String::from(
+                "Graphic characters received at the right margin replace the last character of the line."
+            ),
+            Self::TextCursorEnableMode => String::from(
+                "The text cursor is hidden."
+            ),
+            Self::NormalMouseTracking
+            | Self::ButtonEventMouseTracking
+            | Self::AnyEventMouseTracking => String::from(
+                "Mouse events are not reported."
+            ),
+            Self::FocusEventMode => String::from(
+                "Gaining and losing the focus is not reported."
+            ),
+            Self::SgrMouseMode => String::from(
+                "Mouse events are reported with the default encoding."
+            ),
+            Self::AlternateScreenBuffer => String::from(
+                "The normal screen buffer is shown, and the saved cursor position is restored."
+            ),
+            Self::BracketedPasteMode => String::from(
+                "Pasted text is received like typed text."
+            ),
+            Self::SynchronizedOutput => String::from(
+                "Updates of the screen are shown immediately."
+            ),
+        }
+    }
+
+    fn explain_set(&self) -> String {
+        match self {
+            Self::OriginMode => String::from(
+                "Cursor positions are relative to the upper left corner of the scrolling region."
+            ),
+            Self::AutoWrapMode => String::from(
+                "Graphic characters received at the right margin are imaged at the beginning of the next line."
+            ),
+            Self::TextCursorEnableMode => String::from(
+                "The text cursor is visible."
+            ),
+            Self::NormalMouseTracking => String::from(
+                "Presses and releases of mouse buttons are reported."
+            ),
+            Self::ButtonEventMouseTracking => String::from(
+                "Presses and releases of mouse buttons, and movements of the mouse while a button is pressed are reported."
+            ),
+            Self::AnyEventMouseTracking => String::from(
+                "Presses and releases of mouse buttons, and all movements of the mouse are reported."
+            ),
+            Self::FocusEventMode => String::from(
+                "Gaining and losing the focus is reported."
+            ),
+            Self::SgrMouseMode => String::from(
+                "Mouse events are reported with the SGR encoding."
+            ),
+            Self::AlternateScreenBuffer => String::from(
+                "The cursor position is saved, and the alternate screen buffer is cleared and shown."
+            ),
+            Self::BracketedPasteMode => String::from(
+                "Pasted text is enclosed in 'CSI 200 ~' and 'CSI 201 ~'."
+            ),
+            Self::SynchronizedOutput => String::from(
+                "Updates of the screen are held back, until this mode is reset."
+            ),
+        }
     }
 }
 
@@ -3655,23 +3518,6 @@ impl ExplainSelection for PresentationVariant {
     }
 }
 
-impl FromStr for CharacterOrientation {
-    type Err = Infallible;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(match s {
-            "1" => Self::Rotate45,
-            "2" => Self::Rotate90,
-            "3" => Self::Rotate135,
-            "4" => Self::Rotate180,
-            "5" => Self::Rotate225,
-            "6" => Self::Rotate270,
-            "7" => Self::Rotate315,
-            _ => Self::Normal,
-        })
-    }
-}
-
 impl ExplainSelection for CharacterOrientation {
     fn explain(&self) -> String {
         match self {
@@ -3687,17 +3533,6 @@ impl ExplainSelection for CharacterOrientation {
     }
 }
 
-impl FromStr for CharacterPath {
-    type Err = Infallible;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(match s {
-            "2" => Self::RightToLeft,
-            _ => Self::LefToRight,
-        })
-    }
-}
-
 impl ExplainSelection for CharacterPath {
     fn explain(&self) -> String {
         match self {
@@ -3707,18 +3542,6 @@ impl ExplainSelection for CharacterPath {
     }
 }
 
-impl FromStr for CharacterPathScope {
-    type Err = Infallible;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(match s {
-            "1" => Self::InPresentationComponent,
-            "2" => Self::InDataComponent,
-            _ => Self::Undefined,
-        })
-    }
-}
-
 impl ExplainSelection for CharacterPathScope {
     fn explain(&self) -> String {
         match self {
@@ -3743,18 +3566,6 @@ impl ExplainSelection for CharacterPathScope {
     }
 }
 
-impl FromStr for StringDirection {
-    type Err = Infallible;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(match s {
-            "1" => Self::StartLeftToRight,
-            "2" => Self::StartRightToLeft,
-            _ => Self::End,
-        })
-    }
-}
-
 impl ExplainSelection for StringDirection {
     fn explain(&self) -> String {
         match self {
@@ -3771,20 +3582,6 @@ impl ExplainSelection for StringDirection {
     }
 }
 
-impl FromStr for EditingExtend {
-    type Err = Infallible;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(match s {
-            "1" => Self::ActiveLine,
-            "2" => Self::ActiveField,
-            "3" => Self::QualifiedArea,
-            "4" => Self::All,
-            _ => Self::ActivePage,
-        })
-    }
-}
-
 impl ExplainSelection for EditingExtend {
     fn explain(&self) -> String {
         match self {
@@ -3799,21 +3596,6 @@ impl ExplainSelection for EditingExtend {
     }
 }
 
-impl FromStr for Load {
-    type Err = Infallible;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(match s {
-            "0" => Self::None,
-            value @ _ => Self::Bin(
-                value
-                    .parse::<u32>()
-                    .expect("Expected valid value for Load directive"),
-            ),
-        })
-    }
-}
-
 impl ExplainSelection for Load {
     fn explain(&self) -> String {
         match self {
@@ -3823,21 +3605,6 @@ impl ExplainSelection for Load {
     }
 }
 
-impl FromStr for Stack {
-    type Err = Infallible;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(match s {
-            "0" => Self::None,
-            value @ _ => Self::Stacker(
-                value
-                    .parse::<u32>()
-                    .expect("Expected valid value for Load directive"),
-            ),
-        })
-    }
-}
-
 impl ExplainSelection for Stack {
     fn explain(&self) -> String {
         match self {
@@ -3847,68 +3614,16 @@ impl ExplainSelection for Stack {
     }
 }
 
-impl FromStr for GraphicRendition {
-    type Err = Infallible;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(match s {
-            "1" => Self::HighIntensity,
-            "2" => Self::LowIntensity,
-            "3" => Self::Italicized,
-            "4" => Self::Underlined,
-            "5" => Self::SlowlyBlinking,
-            "6" => Self::RapidlyBlinking,
-            "7" => Self::Negative,
-            "8" => Self::Concealed,
-            "9" => Self::CrossedOut,
-            "10" => Self::PrimaryFont,
-            "11" => Self::FirstAlternativeFont,
-            "12" => Self::SecondAlternativeFont,
-            "13" => Self::ThirdAlternativeFont,
-            "14" => Self::ForthAlternativeFont,
-            "15" => Self::FifthAlternativeFont,
-            "16" => Self::SixthAlternativeFont,
-            "17" => Self::SeventhAlternativeFont,
-            "18" => Self::EighthAlternativeFont,
-            "19" => Self::NinthAlternativeFont,
-            "20" => Self::Fraktur,
-            "21" => Self::DoublyUnderlined,
-            "22" => Self::NormalIntensity,
-            "23" => Self::NormalStyle,
-            "24" => Self::NotUnderlined,
-            "25" => Self::NotBlinking,
-            "27" => Self::Positive,
-            "28" => Self::Revealed,
-            "29" => Self::NotCrossedOut,
-            "30" => Self::BlackForeground,
-            "31" => Self::RedForeground,
-            "32" => Self::GreenForeground,
-            "33" => Self::YellowForeground,
-            "34" => Self::BlueForeground,
-            "35" => Self::MagentaForeground,
-            "36" => Self::CyanForeground,
-            "37" => Self::WhiteForeground,
-            "39" => Self::DefaultForeground,
-            "40" => Self::BlackBackground,
-            "41" => Self::RedBackground,
-            "42" => Self::GreenBackground,
-            "43" => Self::YellowBackground,
-            "44" => Self::BlueBackground,
-            "45" => Self::MagentaBackground,
-            "46" => Self::CyanBackground,
-            "47" => Self::WhiteBackground,
-            "49" => Self::DefaultBackground,
-            "51" => Self::Framed,
-            "52" => Self::Encircled,
-            "53" => Self::Overlined,
-            "54" => Self::NotFramed,
-            "55" => Self::NotOverlined,
-            "60" => Self::IdeogramUnderline,
-            "61" => Self::IdeogramUnderline,
-            "62" => Self::IdeogramStressMarking,
-            "63" => Self::CancelIdeogramRendition,
-            _ => Self::Default,
-        })
+impl ExplainSelection for UnderlineStyle {
+    fn explain(&self) -> String {
+        match self {
+            Self::None => String::from("Not underlined."),
+            Self::Single => String::from("Singly underlined."),
+            Self::Double => String::from("Doubly underlined."),
+            Self::Curly => String::from("Curly underlined."),
+            Self::Dotted => String::from("Dotted underlined."),
+            Self::Dashed => String::from("Dashed underlined."),
+        }
     }
 }
 
@@ -3916,6 +3631,11 @@ impl ExplainSelection for GraphicRendition {
     fn explain(&self) -> String {
         match self {
             Self::Default => String::from("Default rendition, cancel all effects."),
+            Self::Foreground(color) => format!("Foreground {}.", explain_color(color)),
+            Self::Background(color) => format!("Background {}.", explain_color(color)),
+            Self::UnderlineColor(color) => format!("Underline {}.", explain_color(color)),
+            Self::UnderlineStyle(style) => style.explain(),
+            Self::DefaultUnderlineColor => String::from("Default underline color."),
             Self::HighIntensity => String::from("Bold or increased intensity."),
             Self::LowIntensity => String::from("Faint, decreased intensity or second color."),
             Self::Italicized => String::from("Italicized."),
@@ -3975,26 +3695,26 @@ impl ExplainSelection for GraphicRendition {
             }
             Self::IdeogramStressMarking => String::from("Ideogram stress marking."),
             Self::CancelIdeogramRendition => String::from("Cancel Ideogram rendition settings."),
+            Self::BrightBlackForeground => String::from("Bright black foreground color."),
+            Self::BrightRedForeground => String::from("Bright red foreground color."),
+            Self::BrightGreenForeground => String::from("Bright green foreground color."),
+            Self::BrightYellowForeground => String::from("Bright yellow foreground color."),
+            Self::BrightBlueForeground => String::from("Bright blue foreground color."),
+            Self::BrightMagentaForeground => String::from("Bright magenta foreground color."),
+            Self::BrightCyanForeground => String::from("Bright cyan foreground color."),
+            Self::BrightWhiteForeground => String::from("Bright white foreground color."),
+            Self::BrightBlackBackground => String::from("Bright black background color."),
+            Self::BrightRedBackground => String::from("Bright red background color."),
+            Self::BrightGreenBackground => String::from("Bright green background color."),
+            Self::BrightYellowBackground => String::from("Bright yellow background color."),
+            Self::BrightBlueBackground => String::from("Bright blue background color."),
+            Self::BrightMagentaBackground => String::from("Bright magenta background color."),
+            Self::BrightCyanBackground => String::from("Bright cyan background color."),
+            Self::BrightWhiteBackground => String::from("Bright white background color."),
         }
     }
 }
 
-impl FromStr for CharacterSpacing {
-    type Err = Infallible;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(match s {
-            "1" => Self::TwelveCharacters,
-            "2" => Self::FifteenCharacters,
-            "3" => Self::SixCharacters,
-            "4" => Self::ThreeCharacters,
-            "5" => Self::NineCharacters,
-            "6" => Self::FourCharacters,
-            _ => Self::TenCharacters,
-        })
-    }
-}
-
 impl ExplainSelection for CharacterSpacing {
     fn explain(&self) -> String {
         match self {
@@ -4023,17 +3743,6 @@ impl ExplainSelection for CharacterSpacing {
     }
 }
 
-impl FromStr for MovementDirection {
-    type Err = Infallible;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(match s {
-            "1" => Self::Opposite,
-            _ => Self::Normal,
-        })
-    }
-}
-
 impl ExplainSelection for MovementDirection {
     fn explain(&self) -> String {
         match self {
@@ -4047,23 +3756,6 @@ impl ExplainSelection for MovementDirection {
     }
 }
 
-impl FromStr for PresentationDirection {
-    type Err = Infallible;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(match s {
-            "1" => Self::VerticalLinesRightToLeftTopToBottom,
-            "2" => Self::VerticalLinesLeftToRightTopToBottom,
-            "3" => Self::HorizontalLinesTopToBottomRightToLeft,
-            "4" => Self::VerticalLinesLeftToRightBottomToTop,
-            "5" => Self::HorizontalLinesBottomToTopRightToLeft,
-            "6" => Self::HorizontalLinesBottomToTopLefToRight,
-            "7" => Self::VerticalLinesRightToLeftBottomToTop,
-            _ => Self::HorizontalLinesTopToBottomLeftToRight,
-        })
-    }
-}
-
 impl ExplainSelection for PresentationDirection {
     fn explain(&self) -> String {
         match self {
@@ -4095,18 +3787,6 @@ impl ExplainSelection for PresentationDirection {
     }
 }
 
-impl FromStr for PresentationDirectionScope {
-    type Err = Infallible;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(match s {
-            "1" => Self::InPresentationComponent,
-            "2" => Self::InDataComponent,
-            _ => Self::Undefined,
-        })
-    }
-}
-
 impl ExplainSelection for PresentationDirectionScope {
     fn explain(&self) -> String {
         match self {
@@ -4117,18 +3797,6 @@ impl ExplainSelection for PresentationDirectionScope {
     }
 }
 
-impl FromStr for PrintQuality {
-    type Err = Infallible;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(match s {
-            "1" => Self::MediumQualityMediumSpeed,
-            "2" => Self::LowQualityHighSpeed,
-            _ => Self::HighQualityLowSpeed,
-        })
-    }
-}
-
 impl ExplainSelection for PrintQuality {
     fn explain(&self) -> String {
         match self {
@@ -4141,17 +3809,6 @@ impl ExplainSelection for PrintQuality {
     }
 }
 
-impl FromStr for ReversedString {
-    type Err = Infallible;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(match s {
-            "1" => Self::Start,
-            _ => Self::End,
-        })
-    }
-}
-
 impl ExplainSelection for ReversedString {
     fn explain(&self) -> String {
         match self {
@@ -4163,24 +3820,6 @@ impl ExplainSelection for ReversedString {
     }
 }
 
-impl FromStr for SizeUnit {
-    type Err = Infallible;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(match s {
-            "1" => Self::Millimetre,
-            "2" => Self::ComputerDecipoint,
-            "3" => Self::Decidot,
-            "4" => Self::Mil,
-            "5" => Self::BasicMeasuringUnit,
-            "6" => Self::Micrometer,
-            "7" => Self::Pixel,
-            "8" => Self::Decipoint,
-            _ => Self::Character,
-        })
-    }
-}
-
 impl ExplainSelection for SizeUnit {
     fn explain(&self) -> String {
         match self {
@@ -4205,25 +3844,6 @@ impl ExplainSelection for SizeUnit {
     }
 }
 
-impl FromStr for LineSpacing {
-    type Err = Infallible;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(match s {
-            "1" => Self::FourLinesPer25,
-            "2" => Self::ThreeLinesPer25,
-            "3" => Self::TwelveLinesPer25,
-            "4" => Self::EightLinesPer25,
-            "5" => Self::SixLinesPer30,
-            "6" => Self::FourLinesPer30,
-            "7" => Self::ThreeLinesPer30,
-            "8" => Self::TwelveLinesPer30,
-            "9" => Self::TwoLinesPer25,
-            _ => Self::SixLinesPer25,
-        })
-    }
-}
-
 impl ExplainSelection for LineSpacing {
     fn explain(&self) -> String {
         match self {
@@ -4241,21 +3861,6 @@ impl ExplainSelection for LineSpacing {
     }
 }
 
-impl FromStr for ClearTabulation {
-    type Err = Infallible;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(match s {
-            "1" => Self::LineTabulationStopActiveLine,
-            "2" => Self::AllCharacterTabulationStopsActiveLine,
-            "3" => Self::AllCharacterTabulationStops,
-            "4" => Self::AllTabulationStops,
-            "5" => Self::AllTabulationStops,
-            _ => Self::CharacterTabulationStopActivePosition,
-        })
-    }
-}
-
 impl ExplainSelection for ClearTabulation {
     fn explain(&self) -> String {
         match self {
@@ -4277,9 +3882,158 @@ impl ExplainSelection for ClearTabulation {
     }
 }
 
+/// Implements the deprecated conversion from the string representation of a selective parameter value.
+macro_rules! from_str {
+    ($($selection:ident = $fallback:ident),* $(,)?) => {
+        $(
+            /// Deprecated, use `TryFrom<u32>` instead.
+            ///
+            /// Strings that are not a valid selective parameter value are converted into the same variant as in
+            /// version 1.0.1.
+            impl FromStr for $selection {
+                type Err = Infallible;
+
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    Ok(s.parse::<u32>()
+                        .ok()
+                        .and_then(|value| Self::try_from(value).ok())
+                        .unwrap_or($selection::$fallback))
+                }
+            }
+        )*
+    };
+}
+
+from_str!(
+    TabulationControl = SetCharacterTabulationStop,
+    DeviceAttributes = Request,
+    AreaQualification = UnprotectedUnguarded,
+    DeviceStatusReport = Ready,
+    EraseArea = ActivePositionToEnd,
+    ErasePage = ActivePositionToEnd,
+    EraseField = ActivePositionToEnd,
+    EraseLine = ActivePositionToEnd,
+    Font = Primary,
+    GraphicCharacterCombination = CombineTwo,
+    IdentifyDeviceControlString = Diagnostic,
+    Justification = None,
+    MediaCopy = BeginTransferToPrimary,
+    PresentationExpandContract = Normal,
+    PageFormat = TallBasicText,
+    ParallelText = End,
+    Alignment = LineHome,
+    Mode = GuardedAreaTransferMode,
+    PresentationVariant = Default,
+    CharacterOrientation = Normal,
+    CharacterPath = LefToRight,
+    CharacterPathScope = Undefined,
+    StringDirection = End,
+    EditingExtend = ActivePage,
+    Load = None,
+    Stack = None,
+    GraphicRendition = Default,
+    CharacterSpacing = TenCharacters,
+    MovementDirection = Normal,
+    PresentationDirection = HorizontalLinesTopToBottomLeftToRight,
+    PresentationDirectionScope = Undefined,
+    PrintQuality = HighQualityLowSpeed,
+    ReversedString = End,
+    SizeUnit = Character,
+    LineSpacing = SixLinesPer25,
+    ClearTabulation = CharacterTabulationStopActivePosition,
+);
+
 #[cfg(test)]
 mod tests {
-    use crate::{c0::CR, explain::Explain};
+    use crate::{
+        c0::CR,
+        control_sequences::{
+            Color, DeviceAttributes, ErasePage, GraphicRendition, UnderlineStyle, DA, ED,
+            FUNCTIONS, SGR,
+        },
+        explain::{Explain, ExplainMode},
+        modes::{Mode, PrivateMode},
+        ControlFunction, Parameter, PrivateParameter,
+    };
+
+    /// Test the explanation of graphic renditions with colors of the extended color palettes
+    #[test]
+    fn explain_extended_colors() {
+        let sgr = SGR(Some(vec![
+            GraphicRendition::HighIntensity,
+            GraphicRendition::Foreground(Color::Indexed(208)),
+            GraphicRendition::Background(Color::Rgb(255, 0, 0)),
+        ]));
+        assert_eq!(
+            sgr.short_description(),
+            concat!(
+                "Change the representation of following text. Bold or increased intensity. Foreground color 208 ",
+                "of the palette of 256 colors. Background direct color with red 255, green 0 and blue 0."
+            )
+        );
+
+        let invalid_color = ControlFunction::new_sequence(
+            "m",
+            [
+                Parameter::from(38),
+                Parameter::from(5),
+                Parameter::from(300),
+            ],
+        );
+        assert_eq!(
+            invalid_color.short_description(),
+            "Change the representation of following text. Parameter 2 has the invalid selective value 300."
+        );
+
+        let missing_color =
+            ControlFunction::new_sequence("m", [Parameter::from(38), Parameter::from(5)]);
+        assert_eq!(
+            missing_color.short_description(),
+            "Change the representation of following text. Parameter 2 is required, but omitted."
+        );
+
+        let sub_parameter =
+            ControlFunction::new_sequence("m", [Parameter::new([Some(1), Some(2)])]);
+        assert_eq!(
+            sub_parameter.short_description(),
+            "Change the representation of following text. Parameter 0 has unexpected sub-parameters."
+        );
+    }
+
+    /// Test the explanation of graphic renditions that are common extensions, or invalid
+    #[test]
+    fn explain_common_extensions() {
+        let sgr = SGR(Some(vec![
+            GraphicRendition::BrightRedForeground,
+            GraphicRendition::BrightWhiteBackground,
+        ]));
+        assert_eq!(sgr, "\x1b[91;107m");
+        assert_eq!(
+            sgr.short_description(),
+            concat!(
+                "Change the representation of following text. Bright red foreground color. Bright white ",
+                "background color."
+            )
+        );
+
+        let underline = SGR(Some(vec![
+            GraphicRendition::UnderlineStyle(UnderlineStyle::Curly),
+            GraphicRendition::UnderlineColor(Color::Indexed(1)),
+        ]));
+        assert_eq!(
+            underline.short_description(),
+            concat!(
+                "Change the representation of following text. Curly underlined. Underline color 1 of the palette ",
+                "of 256 colors."
+            )
+        );
+
+        let invalid = ControlFunction::new_sequence("m", [Parameter::from(1), Parameter::from(99)]);
+        assert_eq!(
+            invalid.short_description(),
+            "Change the representation of following text. Parameter 1 has the invalid selective value 99."
+        );
+    }
 
     /// Test the output of short_name
     #[test]
@@ -4320,4 +4074,167 @@ mod tests {
         )
     )
     }
+
+    /// Test that control sequences with other intermediate bytes are explained as private use
+    #[test]
+    fn explain_intermediate_bytes() {
+        let soft_reset = ControlFunction::private_use("!p", vec![]).unwrap();
+        assert_eq!(soft_reset.short_name(), None);
+        assert_eq!(soft_reset.long_name(), "Private Use / Experimental Use");
+
+        let cursor_style = ControlFunction::private_use(" q", vec![String::from("2")]).unwrap();
+        assert_eq!(cursor_style.long_name(), "Private Use / Experimental Use");
+    }
+
+    /// Test that control sequences with private parameters are explained as private use
+    #[test]
+    fn explain_private_parameter() {
+        let secondary_device_attributes =
+            DA(None).with_private_parameter(PrivateParameter::GreaterThan);
+        assert_eq!(secondary_device_attributes.short_name(), None);
+        assert_eq!(
+            secondary_device_attributes.long_name(),
+            "Private Use / Experimental Use"
+        );
+    }
+
+    /// Test that the intermediate bytes and final byte of each control sequence identify the control sequence
+    #[test]
+    fn explain_all_control_sequences() {
+        for &(name, function) in FUNCTIONS {
+            let control_sequence = ControlFunction::new_sequence(function, [] as [Parameter; 0]);
+            assert_eq!(control_sequence.short_name(), Some(name));
+        }
+    }
+
+    /// Test that omitted and invalid selective parameter values are explained
+    #[test]
+    fn explain_omitted_and_invalid_selections() {
+        let request = ControlFunction::new_sequence("c", [] as [Parameter; 0]);
+        assert_eq!(
+            request.short_description(),
+            "Request Device Attribute identification from the receiving device."
+        );
+
+        let sub_parameters =
+            ControlFunction::new_sequence("c", [Parameter::new(vec![Some(0), Some(1)])]);
+        assert_eq!(
+            sub_parameters.short_description(),
+            "Request Device Attribute identification from the receiving device."
+        );
+
+        let identify_device_control_string =
+            ControlFunction::new_sequence(" O", [] as [Parameter; 0]);
+        assert_eq!(identify_device_control_string.short_name(), Some("IDCS"));
+        assert_eq!(
+            identify_device_control_string.short_description(),
+            "Subsequent 'Device Control Strings' (DCS) are for private use."
+        );
+
+        let erase_page = ControlFunction::new_sequence("J", [] as [Parameter; 0]);
+        assert_eq!(erase_page.short_description(), ED(None).short_description());
+
+        let invalid_erase_page = ControlFunction::new_sequence("J", [Parameter::from(99)]);
+        assert_eq!(
+            invalid_erase_page.short_description(),
+            "Parameter 0 has the invalid selective value 99."
+        );
+        assert_eq!(
+            invalid_erase_page.long_description(),
+            "Parameter 0 has the invalid selective value 99."
+        );
+
+        let character_path =
+            ControlFunction::new_sequence(" k", [Parameter::from(2), Parameter::from(99)]);
+        assert_eq!(
+            character_path.short_description(),
+            "Parameter 1 has the invalid selective value 99."
+        );
+    }
+
+    /// Test the deprecated conversion from strings, which forwards to `TryFrom<u32>`
+    #[test]
+    fn deprecated_from_str() {
+        assert_eq!("2".parse(), Ok(ErasePage::BeginToEnd));
+        assert_eq!("99".parse(), Ok(ErasePage::ActivePositionToEnd));
+        assert_eq!("4".parse(), Ok(Mode::InsertionReplacementMode));
+        assert_eq!("17".parse(), Ok(Mode::SelectedAreaTransferMode));
+        assert_eq!("x".parse(), Ok(Mode::GuardedAreaTransferMode));
+        assert_eq!("1234".parse(), Ok(DeviceAttributes::Identify(1234)));
+    }
+
+    /// Test that DEC private modes are explained
+    #[test]
+    fn explain_private_modes() {
+        let alternate_screen = PrivateMode::AlternateScreenBuffer.set();
+        assert_eq!(alternate_screen.short_name(), Some("DECSET"));
+        assert_eq!(alternate_screen.long_name(), "DEC Private Mode Set");
+        assert_eq!(
+            alternate_screen.short_description(),
+            "Set the following DEC Private Modes: Alternate Screen Buffer."
+        );
+        assert_eq!(
+            alternate_screen.long_description(),
+            "The cursor position is saved, and the alternate screen buffer is cleared and shown."
+        );
+
+        let mouse =
+            ControlFunction::new_sequence("l", [Parameter::from(1006), Parameter::from(1047)])
+                .with_private_parameter(PrivateParameter::QuestionMark);
+        assert_eq!(mouse.short_name(), Some("DECRST"));
+        assert_eq!(mouse.long_name(), "DEC Private Mode Reset");
+        assert_eq!(
+            mouse.short_description(),
+            "Reset the following DEC Private Modes: SGR Mouse Mode, Unknown Private Mode 1047."
+        );
+        assert_eq!(
+            mouse.long_description(),
+            "Mouse events are reported with the default encoding. The unknown Private Mode 1047 is reset."
+        );
+    }
+
+    /// Test that missing and unknown modes are explained
+    #[test]
+    fn explain_missing_and_unknown_modes() {
+        let no_private_mode = ControlFunction::new_sequence("h", [] as [Parameter; 0])
+            .with_private_parameter(PrivateParameter::QuestionMark);
+        assert_eq!(
+            no_private_mode.short_description(),
+            "Set the following DEC Private Modes: no mode given."
+        );
+        assert_eq!(no_private_mode.long_description(), "No mode is given.");
+
+        let unknown_mode =
+            ControlFunction::new_sequence("h", [Parameter::from(17), Parameter::from(99)]);
+        assert_eq!(
+            unknown_mode.short_description(),
+            "Set the following Modes: Selected Area Transfer Mode, Unknown Mode 99."
+        );
+        assert_eq!(
+            unknown_mode.long_description(),
+            format!(
+                "{} The unknown Mode 99 is set.",
+                Mode::SelectedAreaTransferMode.explain_set()
+            )
+        );
+
+        let missing_mode = ControlFunction::new_sequence(
+            "l",
+            [
+                Parameter::new(vec![Some(22), Some(1)]),
+                Parameter::new(vec![None]),
+            ],
+        );
+        assert_eq!(
+            missing_mode.short_description(),
+            "Reset the following Modes: Zero Default Mode, no mode given."
+        );
+        assert_eq!(
+            missing_mode.long_description(),
+            format!(
+                "{} A missing mode is ignored.",
+                Mode::ZeroDefaultMode.explain_reset()
+            )
+        );
+    }
 }
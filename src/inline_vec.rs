@@ -0,0 +1,125 @@
+//! Storage for a small number of elements, that does not allocate on the heap.
+//!
+//! Most control sequences have only a few parameters, and most parameters have only a single value. Storing them in an
+//! [`InlineVec`] allows to create and format control sequences without any heap allocation.
+
+use std::{
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+    mem,
+    ops::Deref,
+};
+
+/// The value of the unused inline slots of an [`InlineVec`].
+pub(crate) trait Filler {
+    /// The value of an unused inline slot.
+    const FILLER: Self;
+}
+
+impl Filler for Option<u32> {
+    const FILLER: Self = None;
+}
+
+/// A vector that stores up to `N` elements inline. Only if more than `N` elements are pushed, all elements are moved to
+/// the heap.
+#[derive(Clone)]
+#[allow(clippy::large_enum_variant)]
+pub(crate) enum InlineVec<T, const N: usize> {
+    /// Up to `N` elements, stored inline. Only the first `length` elements are in use.
+    Inline { length: u8, elements: [T; N] },
+    /// More than `N` elements, stored on the heap. The vector is boxed, so that it does not take more space than a
+    /// pointer in the vector itself.
+    #[allow(clippy::box_collection)]
+    Heap(Box<Vec<T>>),
+}
+
+impl<T: Filler, const N: usize> InlineVec<T, N> {
+    /// Creates a new, empty vector.
+    pub(crate) const fn new() -> Self {
+        InlineVec::Inline {
+            length: 0,
+            elements: [T::FILLER; N],
+        }
+    }
+
+    /// Appends an element to the end of the vector.
+    pub(crate) fn push(&mut self, element: T) {
+        match self {
+            InlineVec::Inline { length, elements } if usize::from(*length) < N => {
+                elements[usize::from(*length)] = element;
+                *length += 1;
+            }
+            InlineVec::Inline { elements, .. } => {
+                let mut heap = Vec::with_capacity(N * 2);
+                heap.extend(
+                    elements
+                        .iter_mut()
+                        .map(|element| mem::replace(element, T::FILLER)),
+                );
+                heap.push(element);
+                *self = InlineVec::Heap(Box::new(heap));
+            }
+            InlineVec::Heap(heap) => heap.push(element),
+        }
+    }
+}
+
+impl<T, const N: usize> Deref for InlineVec<T, N> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            InlineVec::Inline { length, elements } => &elements[..usize::from(*length)],
+            InlineVec::Heap(heap) => heap,
+        }
+    }
+}
+
+impl<T: Filler, const N: usize> FromIterator<T> for InlineVec<T, N> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut inline_vec = InlineVec::new();
+        for element in iter {
+            inline_vec.push(element);
+        }
+        inline_vec
+    }
+}
+
+impl<T: Filler, const N: usize, const M: usize> From<[T; M]> for InlineVec<T, N> {
+    fn from(elements: [T; M]) -> Self {
+        elements.into_iter().collect()
+    }
+}
+
+impl<T: PartialEq, const N: usize> PartialEq for InlineVec<T, N> {
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other
+    }
+}
+
+impl<T: Eq, const N: usize> Eq for InlineVec<T, N> {}
+
+impl<T: PartialOrd, const N: usize> PartialOrd for InlineVec<T, N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        (**self).partial_cmp(&**other)
+    }
+}
+
+impl<T: Ord, const N: usize> Ord for InlineVec<T, N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (**self).cmp(&**other)
+    }
+}
+
+impl<T: Hash, const N: usize> Hash for InlineVec<T, N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (**self).hash(state)
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for InlineVec<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
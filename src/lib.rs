@@ -88,6 +88,7 @@
 //! See the following module documentations for a more in-depth introduction to these functions.
 //!
 //! - Working with control strings in module [control_strings].
+//! - Tracking and changing the graphic rendition of text in module [text_style].
 //!
 //! ## Source Material
 //!
@@ -108,7 +109,9 @@
 //! [wikipedia-ansi]: https://en.wikipedia.org/wiki/ANSI_escape_code
 #![deny(missing_debug_implementations, missing_docs)]
 #![allow(clippy::zero_prefixed_literal)]
-use std::{error::Error, fmt, str};
+use std::{borrow::Cow, error::Error, fmt, io, str};
+
+use inline_vec::{Filler, InlineVec};
 
 /// Converts the ascii table notation `xx/yy` into a rust string.
 ///
@@ -142,6 +145,33 @@ macro_rules! ascii {
     };
 }
 
+/// Implements `TryFrom<u32>` for an enum of selective parameter values, whose variants are represented by their
+/// discriminant.
+///
+/// ```ignore
+/// selection!(EraseLine {
+///     ActivePositionToEnd,
+///     BeginToActivePosition,
+///     BeginToEnd,
+/// });
+/// ```
+macro_rules! selection {
+    ($selection:ident { $($variant:ident),* $(,)? }) => {
+        impl TryFrom<u32> for $selection {
+            type Error = crate::InvalidSelection;
+
+            fn try_from(value: u32) -> Result<Self, Self::Error> {
+                $(
+                    if value == $selection::$variant as u32 {
+                        return Ok($selection::$variant);
+                    }
+                )*
+                Err(crate::InvalidSelection { value })
+            }
+        }
+    };
+}
+
 /// Possible errors when specifying a custom control function.
 ///
 /// It is possible to define custom control functions, so called private-use or experimental functions.
@@ -151,12 +181,16 @@ macro_rules! ascii {
 pub enum InvalidControlFunction {
     /// All control function values must be valid ASCII.
     InvalidAsciiError,
-    /// All control functions must have a one- or two-byte function identifier.
+    /// All control functions must have a function identifier, consisting of optional intermediate bytes and a final
+    /// byte.
     InvalidFunctionValueError,
-    /// If the function has an intermediate byte, it must by `02 / 00`. All other intermediate bytes are invalid.
+    /// If the function has intermediate bytes, they must be in the range `02 / 00` to `02 / 15`. All other
+    /// intermediate bytes are invalid.
     InvalidIntermediateByteError,
     /// All private-use functions must be in the range `07 / 00` to `07 / 15`.
     InvalidPrivateUseError,
+    /// All parameters must consist of numeric values, separated by `03 / 10`.
+    InvalidParameterError,
 }
 
 impl fmt::Display for InvalidControlFunction {
@@ -165,27 +199,68 @@ impl fmt::Display for InvalidControlFunction {
             InvalidControlFunction::InvalidAsciiError => {
                 write!(formatter, "Control function must be valid ASCII")
             }
-            InvalidControlFunction::InvalidFunctionValueError => write!(
+            InvalidControlFunction::InvalidFunctionValueError => {
+                write!(formatter, "Control function must have an identifier")
+            }
+            InvalidControlFunction::InvalidIntermediateByteError => write!(
                 formatter,
-                "Control function must have one- or two-byte identifier"
+                "Intermediate bytes must be in range 02/00 to 02/15"
             ),
-            InvalidControlFunction::InvalidIntermediateByteError => {
-                write!(formatter, "Intermediate byte must be 02/00")
-            }
             InvalidControlFunction::InvalidPrivateUseError => write!(
                 formatter,
                 "Private use functions are only allowed in range 07/00 to 07/15"
             ),
+            InvalidControlFunction::InvalidParameterError => write!(
+                formatter,
+                "Parameters must consist of numeric values separated by 03/10"
+            ),
         }
     }
 }
 
 impl Error for InvalidControlFunction {}
 
+/// Error when converting a numeric value into a selective parameter value, or into a mode.
+///
+/// The enums of selective parameter values in [control_sequences], and the [modes][modes::Mode] can be converted from
+/// their numeric value with `TryFrom<u32>`. If the value does not represent any variant of the enum, this error is
+/// returned, which carries the rejected value.
+///
+/// ```
+/// use ansi_control_codes::control_sequences::EraseLine;
+///
+/// assert_eq!(EraseLine::try_from(2), Ok(EraseLine::BeginToEnd));
+/// assert_eq!(EraseLine::try_from(3).unwrap_err().value(), 3);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidSelection {
+    value: u32,
+}
+
+impl InvalidSelection {
+    /// Returns the rejected value.
+    pub fn value(&self) -> u32 {
+        self.value
+    }
+}
+
+impl fmt::Display for InvalidSelection {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "{} is not a valid selective parameter value",
+            self.value
+        )
+    }
+}
+
+impl Error for InvalidSelection {}
+
 /// The different types of control functions.
 ///
-#[derive(Clone, Copy, PartialEq, Eq)]
-enum ControlFunctionType {
+/// The type of a control function is returned by [`ControlFunction::kind`].
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ControlFunctionType {
     /// Elements of the C0 set.
     ///
     /// C0 control functions are represented in 7-bit codes by bit combinations from `00/00` to `01/15`.
@@ -235,6 +310,178 @@ impl fmt::Debug for ControlFunctionType {
     }
 }
 
+/// The private parameter indicator of a control sequence.
+///
+/// A parameter string that starts with one of the bit combinations `03/12` to `03/15` is reserved for private (or
+/// experimental) use by [ECMA-48][ecma-48]. Many private parameter strings are de-facto standards, for example the DEC
+/// private modes that are set with `CSI ? Ps h`.
+///
+/// [ecma-48]: https://www.ecma-international.org/publications-and-standards/standards/ecma-48/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PrivateParameter {
+    /// LESS-THAN SIGN `<` (`03/12`).
+    LessThan,
+
+    /// EQUALS SIGN `=` (`03/13`).
+    Equals,
+
+    /// GREATER-THAN SIGN `>` (`03/14`).
+    GreaterThan,
+
+    /// QUESTION MARK `?` (`03/15`).
+    QuestionMark,
+}
+
+impl PrivateParameter {
+    /// Returns the private parameter indicator that is represented by `byte`, if any.
+    #[cfg(feature = "parser")]
+    pub(crate) fn from_byte(byte: u8) -> Option<Self> {
+        match byte {
+            0x3C => Some(PrivateParameter::LessThan),
+            0x3D => Some(PrivateParameter::Equals),
+            0x3E => Some(PrivateParameter::GreaterThan),
+            0x3F => Some(PrivateParameter::QuestionMark),
+            _ => None,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            PrivateParameter::LessThan => ascii!(03 / 12),
+            PrivateParameter::Equals => ascii!(03 / 13),
+            PrivateParameter::GreaterThan => ascii!(03 / 14),
+            PrivateParameter::QuestionMark => ascii!(03 / 15),
+        }
+    }
+}
+
+impl fmt::Display for PrivateParameter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// A parameter of a control sequence.
+///
+/// A parameter consists of one or more numeric values, each of which can be omitted to use its default value. If a
+/// parameter consists of more than one value, the values after the first are sub-parameters, which are separated by
+/// `03/10` (`:`). Sub-parameters are not specified by [ECMA-48][ecma-48], but by [ITU T.416][itu-t416], and are used
+/// for example to select colors (`38:2::255:0:0`) or underline styles (`4:3`).
+///
+/// ```
+/// use ansi_control_codes::Parameter;
+///
+/// let color = Parameter::new(vec![Some(38), Some(2), None, Some(255), Some(0), Some(0)]);
+/// assert_eq!(color.to_string(), "38:2::255:0:0");
+/// assert_eq!(Parameter::from(4).to_string(), "4");
+/// ```
+///
+/// [ecma-48]: https://www.ecma-international.org/publications-and-standards/standards/ecma-48/
+/// [itu-t416]: https://www.itu.int/rec/T-REC-T.416-199303-I
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Parameter {
+    /// The values of the parameter. Up to two values are stored without heap allocation, which is enough for a
+    /// value with a single sub-parameter, such as an underline style. Colors with their sub-parameters are stored on
+    /// the heap.
+    values: InlineVec<Option<u32>, 2>,
+}
+
+/// The parameters of a control sequence. Up to six parameters are stored without heap allocation, which is enough for
+/// a direct color selected by separate parameters, together with another graphic rendition.
+///
+/// The inline capacities are small, because every [`ControlFunction`] stores its parameters inline, even if it has
+/// none.
+type Parameters = InlineVec<Parameter, 6>;
+
+impl Parameter {
+    /// Creates a new parameter from the given `values`. All values after the first are sub-parameters.
+    ///
+    /// A value of `None` is omitted, which selects its default value.
+    pub fn new(values: impl IntoIterator<Item = Option<u32>>) -> Self {
+        Parameter {
+            values: values.into_iter().collect(),
+        }
+    }
+
+    /// Returns the values of this parameter. All values after the first are sub-parameters.
+    pub fn values(&self) -> &[Option<u32>] {
+        &self.values
+    }
+
+    /// Returns the first value of this parameter, or `None` if it is omitted.
+    pub fn value(&self) -> Option<u32> {
+        self.values.first().copied().flatten()
+    }
+
+    /// Returns the sub-parameters of this parameter, which are all values after the first.
+    pub fn sub_parameters(&self) -> &[Option<u32>] {
+        self.values.get(1..).unwrap_or_default()
+    }
+
+    /// Parses a single parameter from its string representation.
+    ///
+    /// Returns `None` if the string is not a valid parameter.
+    fn parse(value: &str) -> Option<Self> {
+        value
+            .split(ascii!(03 / 10))
+            .map(|value| match value {
+                "" => Some(None),
+                value if value.bytes().all(|byte| byte.is_ascii_digit()) => {
+                    value.parse().ok().map(Some)
+                }
+                _ => None,
+            })
+            .collect::<Option<_>>()
+            .map(|values| Parameter { values })
+    }
+
+    /// Parses a parameter string into its parameters.
+    ///
+    /// Returns `None` if the string contains an invalid parameter.
+    #[cfg(feature = "parser")]
+    pub(crate) fn parse_all(value: &str) -> Option<Parameters> {
+        if value.is_empty() {
+            return Some(Parameters::new());
+        }
+
+        value.split(ascii!(03 / 11)).map(Parameter::parse).collect()
+    }
+}
+
+impl Filler for Parameter {
+    const FILLER: Self = Parameter {
+        values: InlineVec::new(),
+    };
+}
+
+impl From<u32> for Parameter {
+    fn from(value: u32) -> Self {
+        let mut values = InlineVec::new();
+        values.push(Some(value));
+        Parameter { values }
+    }
+}
+
+impl fmt::Display for Parameter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (index, value) in self.values.iter().enumerate() {
+            if index > 0 {
+                write!(f, "{}", ascii!(03 / 10))?;
+            }
+            if let Some(value) = value {
+                write!(f, "{}", value)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for Parameter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self.to_string())
+    }
+}
+
 /// An ansi control function defined in [ECMA-48][ecma-48].
 ///
 /// This struct implements the `PartialEq` trait for String-like types (all types that implement `AsRef<str>`).
@@ -250,17 +497,52 @@ impl fmt::Debug for ControlFunctionType {
 /// }
 /// ```
 ///
+/// ## Ordering
+///
+/// Control functions can be sorted, and used as keys of a `HashMap` or `BTreeMap`. They are ordered by their
+/// [kind][ControlFunction::kind] first (C0, C1, control sequences, independent control functions), then by the bytes
+/// identifying them, and finally by their private parameter indicator and their parameters.
+///
+/// ```
+/// use ansi_control_codes::c0::{BEL, LF};
+/// use ansi_control_codes::c1::NEL;
+/// use ansi_control_codes::control_sequences::CUP;
+///
+/// let mut control_functions = vec![CUP(Some(2), None), NEL, LF, CUP(Some(1), None), BEL];
+/// control_functions.sort();
+///
+/// assert_eq!(control_functions, vec![BEL, LF, NEL, CUP(Some(1), None), CUP(Some(2), None)]);
+/// ```
+///
+/// ## 8-bit Representation
+///
+/// C1 control functions and control sequences are formatted using their 7-bit representation `ESC Fe`. Use
+/// [`write_8bit_to`][ControlFunction::write_8bit_to] or [`to_8bit_bytes`][ControlFunction::to_8bit_bytes] to encode
+/// them using their 8-bit representation instead, in which each C1 control function is represented by a single byte
+/// from `08/00` to `09/15`.
+///
+/// ```
+/// use ansi_control_codes::c1::NEL;
+/// use ansi_control_codes::control_sequences::CUP;
+///
+/// assert_eq!(NEL.to_8bit_bytes(), b"\x85");
+/// assert_eq!(CUP(Some(1), Some(2)).to_8bit_bytes(), b"\x9b1;2H");
+/// ```
+///
 /// [ecma-48]: https://www.ecma-international.org/publications-and-standards/standards/ecma-48/
-#[derive(PartialEq, Eq)]
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct ControlFunction<'a> {
     /// The type of the control function.
     function_type: ControlFunctionType,
 
     /// The byte or byte combination identifying the control function.
-    value: &'a str,
+    value: Cow<'a, str>,
+
+    /// The private parameter indicator that precedes the parameters of a control sequence, if any.
+    private_parameter: Option<PrivateParameter>,
 
     /// An arbitrary number of arguments for this control function.
-    parameters: Vec<String>,
+    parameters: Parameters,
 }
 
 impl ControlFunction<'static> {
@@ -270,8 +552,9 @@ impl ControlFunction<'static> {
     const fn new_c0(value: &'static str) -> Self {
         ControlFunction {
             function_type: ControlFunctionType::C0,
-            value,
-            parameters: vec![],
+            value: Cow::Borrowed(value),
+            private_parameter: None,
+            parameters: Parameters::new(),
         }
     }
 
@@ -281,8 +564,9 @@ impl ControlFunction<'static> {
     const fn new_c1(value: &'static str) -> Self {
         ControlFunction {
             function_type: ControlFunctionType::C1,
-            value,
-            parameters: vec![],
+            value: Cow::Borrowed(value),
+            private_parameter: None,
+            parameters: Parameters::new(),
         }
     }
 
@@ -291,19 +575,21 @@ impl ControlFunction<'static> {
     const fn new_independent_control_function(value: &'static str) -> Self {
         ControlFunction {
             function_type: ControlFunctionType::IndependentControlFunction,
-            value,
-            parameters: vec![],
+            value: Cow::Borrowed(value),
+            private_parameter: None,
+            parameters: Parameters::new(),
         }
     }
 }
 
 impl<'a> ControlFunction<'a> {
     /// Creates a new control function of type [`ControlSequence`][ControlFunctionType::ControlSequence].
-    const fn new_sequence(value: &'a str, parameters: Vec<String>) -> Self {
+    fn new_sequence(value: &'a str, parameters: impl Into<Parameters>) -> Self {
         ControlFunction {
             function_type: ControlFunctionType::ControlSequence,
-            value,
-            parameters,
+            value: Cow::Borrowed(value),
+            private_parameter: None,
+            parameters: parameters.into(),
         }
     }
 
@@ -312,7 +598,22 @@ impl<'a> ControlFunction<'a> {
     /// These functions are not standardized and their function is unknown.
     /// Yet, the standard allows these functions to exist for experimental use.
     ///
-    /// If the specified value lies outside of the valid private use area, this function will return Err.
+    /// The `value` consists of any number of intermediate bytes in the range `02 / 00` to `02 / 15`, followed by the
+    /// final byte.
+    ///
+    /// Each of the `parameters` consists of numeric values, which are separated by `03 / 10` if there are
+    /// sub-parameters.
+    ///
+    /// If the specified value lies outside of the valid private use area, or if a parameter is invalid, this function
+    /// will return Err.
+    ///
+    /// ```
+    /// use ansi_control_codes::ControlFunction;
+    ///
+    /// // DECSTR - soft terminal reset
+    /// let soft_reset = ControlFunction::private_use("!p", vec![]).unwrap();
+    /// assert_eq!(soft_reset.to_string(), "\x1b[!p");
+    /// ```
     pub fn private_use(
         value: &'a str,
         parameters: Vec<String>,
@@ -320,31 +621,258 @@ impl<'a> ControlFunction<'a> {
         if !value.is_ascii() {
             return Err(InvalidControlFunction::InvalidAsciiError);
         }
-        if value.as_bytes().len() > 2 {
+        let Some((function_value, intermediate_bytes)) = value.as_bytes().split_last() else {
             return Err(InvalidControlFunction::InvalidFunctionValueError);
-        }
-        let function_value = if value.as_bytes().len() == 2 {
-            if &value[0..1] != ascii!(02 / 00) {
-                return Err(InvalidControlFunction::InvalidIntermediateByteError);
-            }
-            &value[1..2]
-        } else {
-            &value[0..1]
         };
+        if intermediate_bytes
+            .iter()
+            .any(|intermediate_byte| intermediate_byte >> 4 != 2)
+        {
+            return Err(InvalidControlFunction::InvalidIntermediateByteError);
+        }
 
-        if function_value.as_bytes()[0] >> 4 != 7 {
+        if function_value >> 4 != 7 {
             return Err(InvalidControlFunction::InvalidPrivateUseError);
         }
 
+        let parameters = parameters
+            .iter()
+            .map(|parameter| Parameter::parse(parameter))
+            .collect::<Option<_>>()
+            .ok_or(InvalidControlFunction::InvalidParameterError)?;
+
         Ok(ControlFunction {
             function_type: ControlFunctionType::ControlSequence,
-            value,
+            value: Cow::Borrowed(value),
+            private_parameter: None,
             parameters,
         })
     }
 
-    fn format_parameters(&self) -> String {
-        self.parameters.join(ascii!(03 / 11))
+    /// Marks the parameters of this control sequence as private parameters, by prefixing them with the given private
+    /// parameter indicator.
+    ///
+    /// The private parameter indicator is only part of control sequences, it is ignored for all other types of
+    /// control functions.
+    ///
+    /// ```
+    /// use ansi_control_codes::control_sequences::DA;
+    /// use ansi_control_codes::PrivateParameter;
+    ///
+    /// // Request the secondary device attributes.
+    /// let secondary_device_attributes = DA(None).with_private_parameter(PrivateParameter::GreaterThan);
+    /// assert_eq!(secondary_device_attributes.to_string(), "\x1b[>0c");
+    /// ```
+    pub fn with_private_parameter(mut self, private_parameter: PrivateParameter) -> Self {
+        self.private_parameter = Some(private_parameter);
+        self
+    }
+
+    /// Returns the private parameter indicator of this control sequence, if any.
+    pub fn private_parameter(&self) -> Option<PrivateParameter> {
+        self.private_parameter
+    }
+
+    /// Returns the type of this control function.
+    ///
+    /// ```
+    /// use ansi_control_codes::c0::BEL;
+    /// use ansi_control_codes::control_sequences::CUP;
+    /// use ansi_control_codes::ControlFunctionType;
+    ///
+    /// assert_eq!(BEL.kind(), ControlFunctionType::C0);
+    /// assert_eq!(CUP(None, None).kind(), ControlFunctionType::ControlSequence);
+    /// ```
+    pub fn kind(&self) -> ControlFunctionType {
+        self.function_type
+    }
+
+    /// Returns the final byte identifying this control function.
+    ///
+    /// For `C0` control functions, this is the bit combination of the function itself. For `C1` and independent
+    /// control functions, this is the bit combination following `ESC`. For control sequences, this is the bit
+    /// combination following the parameters and intermediate bytes.
+    ///
+    /// ```
+    /// use ansi_control_codes::c1::NEL;
+    /// use ansi_control_codes::control_sequences::CUP;
+    ///
+    /// assert_eq!(NEL.final_byte(), b'E');
+    /// assert_eq!(CUP(None, None).final_byte(), b'H');
+    /// ```
+    pub fn final_byte(&self) -> u8 {
+        self.value.as_bytes()[self.value.len() - 1]
+    }
+
+    /// Returns the intermediate bytes in the range `02/00` to `02/15` that precede the final byte of a control
+    /// sequence.
+    ///
+    /// All other types of control functions do not have intermediate bytes.
+    ///
+    /// ```
+    /// use ansi_control_codes::control_sequences::SPD;
+    ///
+    /// assert_eq!(SPD(None, None).intermediates(), b" ");
+    /// ```
+    pub fn intermediates(&self) -> &[u8] {
+        &self.value.as_bytes()[..self.value.len() - 1]
+    }
+
+    /// Returns the parameters of this control function.
+    ///
+    /// Only control sequences have parameters.
+    pub fn parameters(&self) -> &[Parameter] {
+        &self.parameters
+    }
+
+    /// Returns the numeric value of the parameter at `index`.
+    ///
+    /// If the parameter or its value is omitted, `default` is returned instead. [ECMA-48][ecma-48] documents the
+    /// default value of each parameter of the control sequences, the generators in [control_sequences] apply the same
+    /// defaults.
+    ///
+    /// ```
+    /// use ansi_control_codes::control_sequences::{CUP, CUU};
+    ///
+    /// let cursor_position = CUP(Some(5), None);
+    /// assert_eq!(cursor_position.numeric_parameter(0, 1), 5);
+    /// assert_eq!(cursor_position.numeric_parameter(1, 1), 1);
+    ///
+    /// // A parameter that is not present at all, also uses the default value.
+    /// assert_eq!(CUU(None).numeric_parameter(3, 1), 1);
+    /// ```
+    ///
+    /// [ecma-48]: https://www.ecma-international.org/publications-and-standards/standards/ecma-48/
+    pub fn numeric_parameter(&self, index: usize, default: u32) -> u32 {
+        self.parameters
+            .get(index)
+            .and_then(Parameter::value)
+            .unwrap_or(default)
+    }
+
+    /// Converts this control function into a control function that owns all of its data.
+    ///
+    /// Control functions that are found by the [`parser`] borrow from the parsed input. The owned control function
+    /// does not, so it can outlive the input, for example to be stored or sent to other threads.
+    ///
+    /// ```
+    /// use ansi_control_codes::control_sequences::CUP;
+    /// use ansi_control_codes::ControlFunction;
+    ///
+    /// let input = String::from("\x1b[3 q");
+    /// let borrowed = ControlFunction::private_use(&input[3..], vec![String::from("3")]).unwrap();
+    /// let owned: ControlFunction<'static> = borrowed.into_owned();
+    /// drop(input);
+    ///
+    /// assert_eq!(owned, "\x1b[3 q");
+    /// ```
+    pub fn into_owned(self) -> ControlFunction<'static> {
+        ControlFunction {
+            function_type: self.function_type,
+            value: Cow::Owned(self.value.into_owned()),
+            private_parameter: self.private_parameter,
+            parameters: self.parameters,
+        }
+    }
+
+    /// Writes this control function to `writer`, using its 7-bit representation.
+    ///
+    /// The control function is written without any heap allocation, as long as the control sequence has at most six
+    /// parameters with at most two values each. This allows to write control functions into a reusable buffer, for
+    /// example in a render loop.
+    ///
+    /// ```
+    /// use ansi_control_codes::control_sequences::CUP;
+    ///
+    /// let mut buffer: Vec<u8> = Vec::with_capacity(64);
+    /// CUP(Some(5), Some(13)).write_to(&mut buffer).unwrap();
+    /// assert_eq!(buffer, b"\x1b[5;13H");
+    /// ```
+    pub fn write_to<W: io::Write + ?Sized>(&self, writer: &mut W) -> io::Result<()> {
+        write!(writer, "{}", self)
+    }
+
+    /// Writes this control function to the formatting `writer`, using its 7-bit representation.
+    ///
+    /// Like [`write_to`][ControlFunction::write_to], the control function is written without heap allocation.
+    ///
+    /// ```
+    /// use ansi_control_codes::control_sequences::CUP;
+    ///
+    /// let mut buffer = String::with_capacity(64);
+    /// CUP(Some(5), Some(13)).format_to(&mut buffer).unwrap();
+    /// assert_eq!(buffer, "\x1b[5;13H");
+    /// ```
+    pub fn format_to<W: fmt::Write + ?Sized>(&self, writer: &mut W) -> fmt::Result {
+        write!(writer, "{}", self)
+    }
+
+    /// Writes this control function to `writer`, using its 8-bit representation.
+    ///
+    /// C1 control functions, including the CONTROL SEQUENCE INTRODUCER ([`CSI`][c1::CSI]) of control sequences, are
+    /// written as single bytes from `08/00` to `09/15`. All other control functions are written in the same way as by
+    /// [`write_to`][ControlFunction::write_to]. The written bytes are not valid UTF-8, they can be parsed with a
+    /// [`ByteTokenStream`][crate::parser::ByteTokenStream] that accepts 8-bit C1 control functions.
+    ///
+    /// ```
+    /// use ansi_control_codes::control_sequences::CUP;
+    ///
+    /// let mut buffer: Vec<u8> = Vec::with_capacity(64);
+    /// CUP(Some(5), Some(13)).write_8bit_to(&mut buffer).unwrap();
+    /// assert_eq!(buffer, b"\x9b5;13H");
+    /// ```
+    pub fn write_8bit_to<W: io::Write + ?Sized>(&self, writer: &mut W) -> io::Result<()> {
+        match self.function_type {
+            ControlFunctionType::C1 => writer.write_all(&[self.c1_8bit()]),
+            ControlFunctionType::ControlSequence => {
+                writer.write_all(&[c1::CSI.c1_8bit()])?;
+                write!(writer, "{}", SequenceBody(self))
+            }
+            ControlFunctionType::C0 | ControlFunctionType::IndependentControlFunction => {
+                self.write_to(writer)
+            }
+        }
+    }
+
+    /// Returns the bytes of this control function in its 8-bit representation.
+    ///
+    /// See [`write_8bit_to`][ControlFunction::write_8bit_to].
+    ///
+    /// ```
+    /// use ansi_control_codes::c1::NEL;
+    ///
+    /// assert_eq!(NEL.to_8bit_bytes(), b"\x85");
+    /// ```
+    pub fn to_8bit_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        self.write_8bit_to(&mut bytes)
+            .expect("Writing into a Vec never fails.");
+        bytes
+    }
+
+    /// Returns the 8-bit representation of a C1 control function, which is the byte from `08/00` to `09/15` that
+    /// corresponds to the byte following ESC in its 7-bit representation.
+    fn c1_8bit(&self) -> u8 {
+        self.value.as_bytes()[0] + 0x40
+    }
+}
+
+/// The private parameter indicator, the parameters and the final bytes of a control sequence, which are the same in
+/// its 7-bit and its 8-bit representation.
+struct SequenceBody<'b, 'a>(&'b ControlFunction<'a>);
+
+impl<'b, 'a> fmt::Display for SequenceBody<'b, 'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(private_parameter) = self.0.private_parameter {
+            write!(f, "{}", private_parameter)?;
+        }
+        for (index, parameter) in self.0.parameters.iter().enumerate() {
+            if index > 0 {
+                write!(f, "{}", ascii!(03 / 11))?;
+            }
+            write!(f, "{}", parameter)?;
+        }
+        write!(f, "{}", self.0.value)
     }
 }
 
@@ -358,8 +886,7 @@ impl<'a> fmt::Display for ControlFunction<'a> {
                 write!(f, "{}{}", c0::ESC, self.value)
             }
             ControlFunctionType::ControlSequence => {
-                let parameters = self.format_parameters();
-                write!(f, "{}{}{}", c1::CSI, parameters, self.value)
+                write!(f, "{}{}", c1::CSI, SequenceBody(self))
             }
         }
     }
@@ -375,11 +902,14 @@ impl<'a> fmt::Debug for ControlFunction<'a> {
             .collect::<Vec<_>>()
             .join(" ");
 
-        f.debug_struct("ControlFunction")
+        let mut debug_struct = f.debug_struct("ControlFunction");
+        debug_struct
             .field("function_type", &self.function_type)
-            .field("function", &function)
-            .field("parameters", &self.parameters)
-            .finish()
+            .field("function", &function);
+        if let Some(private_parameter) = &self.private_parameter {
+            debug_struct.field("private_parameter", private_parameter);
+        }
+        debug_struct.field("parameters", &self.parameters).finish()
     }
 }
 
@@ -423,6 +953,8 @@ impl<'a> PartialEq<ControlFunction<'a>> for String {
     }
 }
 
+mod inline_vec;
+
 pub mod c0;
 pub mod c1;
 pub mod categories;
@@ -430,6 +962,7 @@ pub mod control_sequences;
 pub mod control_strings;
 pub mod independent_control_functions;
 pub mod modes;
+pub mod text_style;
 
 #[cfg(feature = "parser")]
 pub mod parser;
@@ -442,8 +975,20 @@ mod tests {
     use crate::c0::{BEL, ESC};
     use crate::c1::CSI;
     use crate::control_sequences::CNL;
+    use crate::control_sequences::{DeviceAttributes, GraphicRendition, TabulationControl, CUP};
     use crate::independent_control_functions::INT;
-    use crate::ControlFunctionType;
+    use crate::modes::{Mode, PrivateMode};
+    use crate::{
+        ControlFunction, ControlFunctionType, InvalidControlFunction, InvalidSelection, Parameter,
+        PrivateParameter,
+    };
+
+    /// Test that control functions stay small, since the parser creates one for every control function in its input.
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn control_function_size() {
+        assert!(std::mem::size_of::<ControlFunction>() <= 184);
+    }
 
     /// Test the debug format of [`ControlFunctionType`].
     #[test]
@@ -474,6 +1019,22 @@ mod tests {
         );
     }
 
+    /// Test the 8-bit representation of [`ControlFunction`][crate::ControlFunction].
+    #[test]
+    fn write_8bit_control_function() {
+        assert_eq!(BEL.to_8bit_bytes(), b"\x07");
+        assert_eq!(CSI.to_8bit_bytes(), b"\x9b");
+        assert_eq!(CSI.to_string(), "\u{001B}[");
+        assert_eq!(CNL(Some(3)).to_8bit_bytes(), b"\x9b3E");
+        assert_eq!(INT.to_8bit_bytes(), b"\x1ba");
+        assert_eq!(
+            CNL(Some(3))
+                .with_private_parameter(PrivateParameter::QuestionMark)
+                .to_8bit_bytes(),
+            b"\x9b?3E"
+        );
+    }
+
     #[test]
     fn string_equality_c0() {
         let esc_control = ESC;
@@ -613,4 +1174,352 @@ mod tests {
             "Different control codes should not be equal"
         );
     }
+
+    #[test]
+    fn private_use_with_intermediate_bytes() {
+        assert_eq!(
+            ControlFunction::private_use("p", vec![]).unwrap(),
+            "\u{001B}[p"
+        );
+        assert_eq!(
+            ControlFunction::private_use(" q", vec![String::from("2")]).unwrap(),
+            "\u{001B}[2 q"
+        );
+        assert_eq!(
+            ControlFunction::private_use("!\"p", vec![]).unwrap(),
+            "\u{001B}[!\"p"
+        );
+
+        assert!(matches!(
+            ControlFunction::private_use("", vec![]),
+            Err(InvalidControlFunction::InvalidFunctionValueError)
+        ));
+        assert!(matches!(
+            ControlFunction::private_use("0p", vec![]),
+            Err(InvalidControlFunction::InvalidIntermediateByteError)
+        ));
+        assert!(matches!(
+            ControlFunction::private_use(" @", vec![]),
+            Err(InvalidControlFunction::InvalidPrivateUseError)
+        ));
+    }
+
+    #[test]
+    fn display_parameter() {
+        assert_eq!(Parameter::from(5).to_string(), "5");
+        assert_eq!(Parameter::new(vec![None]).to_string(), "");
+        assert_eq!(
+            Parameter::new(vec![Some(58), Some(5), Some(196)]).to_string(),
+            "58:5:196"
+        );
+        assert_eq!(
+            Parameter::new(vec![Some(38), Some(2), None, Some(1), None, Some(3)]).to_string(),
+            "38:2::1::3"
+        );
+    }
+
+    #[test]
+    fn private_use_with_sub_parameters() {
+        let control_function =
+            ControlFunction::private_use("p", vec![String::from("1:2"), String::new()]).unwrap();
+        assert_eq!(
+            control_function.parameters(),
+            vec![
+                Parameter::new(vec![Some(1), Some(2)]),
+                Parameter::new(vec![None])
+            ]
+        );
+        assert_eq!(control_function, "\u{001B}[1:2;p");
+
+        assert!(matches!(
+            ControlFunction::private_use("p", vec![String::from("1;2")]),
+            Err(InvalidControlFunction::InvalidParameterError)
+        ));
+        assert!(matches!(
+            ControlFunction::private_use("p", vec![String::from("?1")]),
+            Err(InvalidControlFunction::InvalidParameterError)
+        ));
+    }
+
+    /// Test the accessors of [`ControlFunction`].
+    #[test]
+    fn control_function_accessors() {
+        assert_eq!(BEL.kind(), ControlFunctionType::C0);
+        assert_eq!(BEL.final_byte(), 0x07);
+        assert_eq!(BEL.intermediates(), b"");
+        assert!(BEL.parameters().is_empty());
+
+        assert_eq!(CSI.kind(), ControlFunctionType::C1);
+        assert_eq!(CSI.final_byte(), b'[');
+
+        assert_eq!(INT.kind(), ControlFunctionType::IndependentControlFunction);
+        assert_eq!(INT.final_byte(), b'a');
+
+        let control_function = ControlFunction::private_use(
+            "!\"p",
+            vec![String::from("4:3"), String::new(), String::from("7")],
+        )
+        .unwrap();
+        assert_eq!(
+            control_function.kind(),
+            ControlFunctionType::ControlSequence
+        );
+        assert_eq!(control_function.final_byte(), b'p');
+        assert_eq!(control_function.intermediates(), b"!\"");
+        assert_eq!(control_function.parameters().len(), 3);
+        assert_eq!(control_function.parameters()[0].value(), Some(4));
+        assert_eq!(control_function.parameters()[0].sub_parameters(), [Some(3)]);
+        assert_eq!(control_function.numeric_parameter(0, 1), 4);
+        assert_eq!(control_function.numeric_parameter(1, 1), 1);
+        assert_eq!(control_function.numeric_parameter(2, 1), 7);
+        assert_eq!(control_function.numeric_parameter(3, 1), 1);
+    }
+
+    /// Test the conversion of numeric values into selective parameter values and modes.
+    #[test]
+    fn selection_try_from_u32() {
+        assert_eq!(
+            TabulationControl::try_from(6),
+            Ok(TabulationControl::ClearAllLineTabulationStops)
+        );
+        assert_eq!(
+            TabulationControl::try_from(7),
+            Err(InvalidSelection { value: 7 })
+        );
+
+        assert_eq!(
+            GraphicRendition::try_from(27),
+            Ok(GraphicRendition::Positive)
+        );
+        assert_eq!(
+            GraphicRendition::try_from(61),
+            Ok(GraphicRendition::IdeogramDoubleUnderline)
+        );
+        assert_eq!(
+            GraphicRendition::try_from(26),
+            Err(InvalidSelection { value: 26 })
+        );
+
+        assert_eq!(Mode::try_from(1), Ok(Mode::GuardedAreaTransferMode));
+        assert_eq!(Mode::try_from(22), Ok(Mode::ZeroDefaultMode));
+        assert_eq!(Mode::try_from(0), Err(InvalidSelection { value: 0 }));
+        assert_eq!(Mode::try_from(19), Err(InvalidSelection { value: 19 }));
+
+        assert_eq!(PrivateMode::try_from(6), Ok(PrivateMode::OriginMode));
+        assert_eq!(
+            PrivateMode::try_from(2026),
+            Ok(PrivateMode::SynchronizedOutput)
+        );
+        assert_eq!(
+            PrivateMode::try_from(1047),
+            Err(InvalidSelection { value: 1047 })
+        );
+        assert_eq!(PrivateMode::TextCursorEnableMode.set(), "\x1b[?25h");
+        assert_eq!(PrivateMode::NormalMouseTracking.reset(), "\x1b[?1000l");
+
+        assert_eq!(DeviceAttributes::from(0), DeviceAttributes::Request);
+        assert_eq!(DeviceAttributes::from(5), DeviceAttributes::Identify(5));
+
+        assert_eq!(
+            InvalidSelection { value: 19 }.to_string(),
+            "19 is not a valid selective parameter value"
+        );
+    }
+
+    /// Test control functions with more parameters and values than are stored inline.
+    #[test]
+    fn many_parameters() {
+        let parameters = vec![
+            String::from("1"),
+            String::from("2:3:4:5:6:7:8"),
+            String::new(),
+            String::from("4"),
+            String::from("5"),
+        ];
+        let control_function = ControlFunction::private_use("p", parameters).unwrap();
+
+        assert_eq!(control_function.parameters().len(), 5);
+        assert_eq!(control_function.parameters()[1].values().len(), 7);
+        assert_eq!(control_function, "\x1b[1;2:3:4:5:6:7:8;;4;5p");
+    }
+
+    /// Test graphic renditions with colors of the extended color palettes.
+    #[test]
+    fn extended_colors() {
+        use crate::control_sequences::{Color, GraphicRendition, UnderlineStyle, SGR};
+
+        assert_eq!(
+            SGR(Some(vec![
+                GraphicRendition::Foreground(Color::Indexed(208)),
+                GraphicRendition::HighIntensity,
+                GraphicRendition::Background(Color::Rgb(1, 2, 3)),
+                GraphicRendition::UnderlineColor(Color::Indexed(9)),
+            ])),
+            "\x1b[38;5;208;1;48;2;1;2;3;58;5;9m"
+        );
+
+        assert_eq!(
+            SGR(Some(vec![
+                GraphicRendition::UnderlineStyle(UnderlineStyle::Double),
+                GraphicRendition::UnderlineColor(Color::Rgb(0, 0, 255)),
+                GraphicRendition::DefaultUnderlineColor,
+            ])),
+            "\x1b[4:2;58;2;0;0;255;59m"
+        );
+
+        assert_eq!(
+            GraphicRendition::try_from(48),
+            Err(InvalidSelection { value: 48 })
+        );
+        assert_eq!(
+            GraphicRendition::try_from(49),
+            Ok(GraphicRendition::DefaultBackground)
+        );
+    }
+
+    /// Test that control functions and modes can be deduplicated and sorted.
+    #[test]
+    fn hash_and_order() {
+        use crate::c0::LF;
+        use std::collections::{BTreeSet, HashSet};
+
+        let control_functions = [LF, CUP(Some(1), Some(2)), LF, CUP(None, None)];
+        let unique = control_functions.iter().cloned().collect::<HashSet<_>>();
+        assert_eq!(unique.len(), 3);
+
+        let sorted = control_functions.into_iter().collect::<BTreeSet<_>>();
+        assert_eq!(
+            sorted.into_iter().collect::<Vec<_>>(),
+            vec![LF, CUP(None, None), CUP(Some(1), Some(2))]
+        );
+
+        let mut modes = vec![
+            Mode::ZeroDefaultMode,
+            Mode::InsertionReplacementMode,
+            Mode::GuardedAreaTransferMode,
+        ];
+        modes.sort();
+        assert_eq!(
+            modes,
+            vec![
+                Mode::GuardedAreaTransferMode,
+                Mode::InsertionReplacementMode,
+                Mode::ZeroDefaultMode
+            ]
+        );
+    }
+
+    /// Test that each graphic rendition is selected by its parameter value.
+    #[test]
+    fn graphic_rendition_values() {
+        use crate::control_sequences::{GraphicRendition, GRAPHIC_RENDITIONS, SGR};
+
+        for &(graphic_rendition, value) in GRAPHIC_RENDITIONS {
+            assert_eq!(GraphicRendition::try_from(value), Ok(graphic_rendition));
+            assert_eq!(
+                SGR(Some(vec![graphic_rendition])).to_string(),
+                format!("\x1b[{}m", value)
+            );
+        }
+    }
+
+    /// Test that each control sequence is decoded into a [`Sequence`][crate::control_sequences::Sequence].
+    #[test]
+    fn decode_all_control_sequences() {
+        use crate::control_sequences::{InvalidSequence, Sequence, FUNCTIONS};
+
+        for &(name, function) in FUNCTIONS {
+            let control_function = ControlFunction::new_sequence(function, [] as [Parameter; 0]);
+            assert_ne!(
+                Sequence::try_from(&control_function).err(),
+                Some(InvalidSequence::NotStandardized),
+                "{} is not decoded",
+                name
+            );
+        }
+    }
+
+    #[test]
+    fn text_style_transitions() {
+        use crate::control_sequences::Font;
+        use crate::control_sequences::{
+            Color, GraphicRendition, InvalidSequence, UnderlineStyle, CUP, SGR,
+        };
+        use crate::text_style::{Blink, Intensity, TextStyle};
+
+        let mut style = TextStyle::default();
+        style
+            .apply(&SGR(Some(vec![
+                GraphicRendition::HighIntensity,
+                GraphicRendition::UnderlineStyle(UnderlineStyle::Curly),
+                GraphicRendition::UnderlineColor(Color::Rgb(255, 0, 0)),
+                GraphicRendition::BrightBlueForeground,
+                GraphicRendition::Background(Color::Indexed(208)),
+                GraphicRendition::ThirdAlternativeFont,
+                GraphicRendition::SlowlyBlinking,
+                GraphicRendition::Fraktur,
+            ])))
+            .unwrap();
+        style.apply(&CUP(None, None)).unwrap();
+        style
+            .apply(&SGR(Some(vec![GraphicRendition::LowIntensity])))
+            .unwrap();
+        assert_eq!(
+            style,
+            TextStyle {
+                intensity: Intensity::Low,
+                underline: UnderlineStyle::Curly,
+                underline_color: Some(Color::Rgb(255, 0, 0)),
+                blink: Blink::Slow,
+                font: Font::Alternative3,
+                foreground: Some(Color::Indexed(12)),
+                background: Some(Color::Indexed(208)),
+                ..Default::default()
+            }
+        );
+
+        // invalid graphic renditions leave the style unchanged
+        let invalid = ControlFunction::new_sequence("m", [Parameter::from(1), Parameter::from(99)]);
+        let mut unchanged = style;
+        assert_eq!(
+            unchanged.apply(&invalid),
+            Err(InvalidSequence::InvalidSelection {
+                index: 1,
+                value: 99
+            })
+        );
+        assert_eq!(unchanged, style);
+
+        // applying the default rendition, or the transition, reaches the other style
+        let mut reset = style;
+        reset.apply(&SGR(None)).unwrap();
+        assert_eq!(reset, TextStyle::default());
+
+        let target = TextStyle {
+            intensity: Intensity::High,
+            inverse: true,
+            foreground: Some(Color::Indexed(12)),
+            ..style
+        };
+        let transition = style.transition_to(&target).unwrap();
+        assert_eq!(transition, "\x1b[22;1;7m");
+        let mut applied = style;
+        applied.apply(&transition).unwrap();
+        assert_eq!(applied, target);
+
+        let inverse = TextStyle {
+            inverse: true,
+            ..Default::default()
+        };
+        assert_eq!(style.transition_to(&inverse).unwrap(), "\x1b[0;7m");
+        assert_eq!(
+            inverse.transition_to(&TextStyle::default()).unwrap(),
+            "\x1b[0m"
+        );
+        assert_eq!(
+            TextStyle::default().transition_to(&style).unwrap(),
+            "\x1b[2;4:3;5;13;94;48;5;208;58;2;255;0;0m"
+        );
+        assert_eq!(style.transition_to(&style), None);
+    }
 }
@@ -45,12 +45,28 @@
 //! print!("{}", modes::Mode::DeviceComponentSelectMode.reset());
 //! ```
 //!
+//! ## DEC Private Modes
+//!
+//! Terminals support many more modes than the ones defined by [ECMA-48][ecma-48]. These modes were introduced by DEC
+//! terminals and xterm, and are set and reset by SET MODE ([`SM`]) and RESET MODE ([`RM`]) with the private parameter
+//! `?` (also known as `DECSET` and `DECRST`). The most widely used ones are available as [`PrivateMode`].
+//!
+//! ```
+//! use ansi_control_codes::modes::PrivateMode;
+//!
+//! // switch to the alternate screen buffer, and hide the cursor.
+//! assert_eq!(PrivateMode::AlternateScreenBuffer.set(), "\x1b[?1049h");
+//! assert_eq!(PrivateMode::TextCursorEnableMode.reset(), "\x1b[?25l");
+//! ```
+//!
 //! [ecma-48]: https://www.ecma-international.org/publications-and-standards/standards/ecma-48/
 
-use crate::ControlFunction;
+use crate::{ControlFunction, Parameter, PrivateParameter};
 
 /// Device Modes.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///
+/// Modes are ordered by their parameter value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Mode {
     /// Guarded Area Transfer Mode `GATM`.
     ///
@@ -496,6 +512,29 @@ pub enum Mode {
     ZeroDefaultMode,
 }
 
+selection!(Mode {
+    GuardedAreaTransferMode,
+    KeyboardActionMode,
+    ControlPresentationMode,
+    InsertionReplacementMode,
+    StatusReportTransferMode,
+    ErasureMode,
+    LineEditingMode,
+    BiDirectionalSupportMode,
+    DeviceComponentSelectMode,
+    CharacterEditingMode,
+    PositioningUnitMode,
+    SendReceiveMode,
+    FormatEffectorActionMode,
+    FormatEffectorTransferMode,
+    MultipleAreaTransferMode,
+    TransferTerminationMode,
+    SelectedAreaTransferMode,
+    TabulationStopMode,
+    GraphicRenditionCombinationMode,
+    ZeroDefaultMode,
+});
+
 use crate::control_sequences::{RM, SM};
 impl Mode {
     /// Set the mode.
@@ -608,3 +647,162 @@ pub const GRCM: Mode = Mode::GraphicRenditionCombinationMode;
 ///
 /// See [`Mode::ZeroDefaultMode`].
 pub const ZDM: Mode = Mode::ZeroDefaultMode;
+
+/// DEC Private Modes.
+///
+/// These modes are not part of ECMA-48, but common extensions that were introduced by DEC terminals and xterm. They
+/// are set with `CSI ? n h` and reset with `CSI ? n l`, see [`set`][PrivateMode::set] and
+/// [`reset`][PrivateMode::reset].
+///
+/// Private modes are ordered by their parameter value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PrivateMode {
+    /// Origin Mode `DECOM`.
+    ///
+    /// ## Reset: Absolute
+    ///
+    /// Cursor positions are relative to the upper left corner of the screen.
+    ///
+    /// ## Set: Relative
+    ///
+    /// Cursor positions are relative to the upper left corner of the scrolling region.
+    OriginMode = 6,
+
+    /// Auto Wrap Mode `DECAWM`.
+    ///
+    /// ## Reset: No Wrap
+    ///
+    /// Graphic characters received at the right margin replace the last character of the line.
+    ///
+    /// ## Set: Wrap
+    ///
+    /// Graphic characters received at the right margin are imaged at the beginning of the next line.
+    AutoWrapMode = 7,
+
+    /// Text Cursor Enable Mode `DECTCEM`.
+    ///
+    /// ## Reset: Hidden
+    ///
+    /// The text cursor is hidden.
+    ///
+    /// ## Set: Visible
+    ///
+    /// The text cursor is visible.
+    TextCursorEnableMode = 25,
+
+    /// Normal Mouse Tracking.
+    ///
+    /// ## Reset: Disabled
+    ///
+    /// Mouse events are not reported.
+    ///
+    /// ## Set: Enabled
+    ///
+    /// Presses and releases of mouse buttons are reported.
+    NormalMouseTracking = 1000,
+
+    /// Button Event Mouse Tracking.
+    ///
+    /// ## Reset: Disabled
+    ///
+    /// Mouse events are not reported.
+    ///
+    /// ## Set: Enabled
+    ///
+    /// Presses and releases of mouse buttons, and movements of the mouse while a button is pressed are reported.
+    ButtonEventMouseTracking = 1002,
+
+    /// Any Event Mouse Tracking.
+    ///
+    /// ## Reset: Disabled
+    ///
+    /// Mouse events are not reported.
+    ///
+    /// ## Set: Enabled
+    ///
+    /// Presses and releases of mouse buttons, and all movements of the mouse are reported.
+    AnyEventMouseTracking = 1003,
+
+    /// Focus Event Mode.
+    ///
+    /// ## Reset: Disabled
+    ///
+    /// Gaining and losing the focus is not reported.
+    ///
+    /// ## Set: Enabled
+    ///
+    /// Gaining the focus is reported with `CSI I`, losing the focus is reported with `CSI O`.
+    FocusEventMode = 1004,
+
+    /// SGR Mouse Mode.
+    ///
+    /// ## Reset: Default Encoding
+    ///
+    /// Mouse events are reported with the default encoding, which is limited to 223 lines and columns.
+    ///
+    /// ## Set: SGR Encoding
+    ///
+    /// Mouse events are reported as `CSI < b ; x ; y M` and `CSI < b ; x ; y m`.
+    SgrMouseMode = 1006,
+
+    /// Alternate Screen Buffer.
+    ///
+    /// ## Reset: Normal Screen
+    ///
+    /// The normal screen buffer is shown, and the cursor position saved when setting this mode is restored.
+    ///
+    /// ## Set: Alternate Screen
+    ///
+    /// The cursor position is saved, and the alternate screen buffer is cleared and shown.
+    AlternateScreenBuffer = 1049,
+
+    /// Bracketed Paste Mode.
+    ///
+    /// ## Reset: Disabled
+    ///
+    /// Pasted text is received like typed text.
+    ///
+    /// ## Set: Enabled
+    ///
+    /// Pasted text is enclosed in `CSI 200 ~` and `CSI 201 ~`.
+    BracketedPasteMode = 2004,
+
+    /// Synchronized Output.
+    ///
+    /// ## Reset: Immediate
+    ///
+    /// Updates of the screen are shown immediately.
+    ///
+    /// ## Set: Synchronized
+    ///
+    /// Updates of the screen are held back, until this mode is reset.
+    SynchronizedOutput = 2026,
+}
+
+selection!(PrivateMode {
+    OriginMode,
+    AutoWrapMode,
+    TextCursorEnableMode,
+    NormalMouseTracking,
+    ButtonEventMouseTracking,
+    AnyEventMouseTracking,
+    FocusEventMode,
+    SgrMouseMode,
+    AlternateScreenBuffer,
+    BracketedPasteMode,
+    SynchronizedOutput,
+});
+
+impl PrivateMode {
+    /// Set the private mode (`DECSET`).
+    pub fn set(self) -> ControlFunction<'static> {
+        ControlFunction::new_sequence(ascii!(06 / 08), [Parameter::from(self as u32)])
+            .with_private_parameter(PrivateParameter::QuestionMark)
+    }
+
+    /// Reset the private mode (`DECRST`).
+    pub fn reset(self) -> ControlFunction<'static> {
+        ControlFunction::new_sequence(ascii!(06 / 12), [Parameter::from(self as u32)])
+            .with_private_parameter(PrivateParameter::QuestionMark)
+    }
+}
@@ -28,6 +28,108 @@
 //! assert_eq!(parts[4], Token::String("multiple lines."));
 //! ```
 //!
+//! ## Parsing Bytes
+//!
+//! Data read from a pty or a serial line is not necessarily valid UTF-8, e.g. because a chunk of data ends in the
+//! middle of a multi-byte character. Use a [`ByteTokenStream`] to parse bytes directly, without validating them first.
+//! All text is emitted as [`Token::Bytes`], and all control strings as [`Token::ByteControlString`].
+//!
+//! C1 control functions can also be represented by single bytes from `08/00` to `09/15`. To recognize this 8-bit
+//! representation, enable it with [`ByteTokenStream::accept_8bit_c1`]. In text, C1 control functions can be represented
+//! by the unicode code points `U+0080` to `U+009F`. To recognize these code points as C1 control functions, enable it
+//! with [`TokenStream::accept_unicode_c1`].
+//!
+//! ```
+//! use ansi_control_codes::c0::BEL;
+//! use ansi_control_codes::parser::{ByteTokenStream, Token};
+//!
+//! let to_be_parsed = b"Ring \xE2\x9D the bell\x07";
+//! let parts: Vec<Token> = ByteTokenStream::from(to_be_parsed).collect();
+//!
+//! assert_eq!(parts[0], Token::Bytes(b"Ring \xE2\x9D the bell"));
+//! assert_eq!(parts[1], Token::ControlFunction(BEL));
+//! ```
+//!
+//! ## Parsing Streams
+//!
+//! When input arrives in chunks, e.g. from consecutive calls to `read()`, a control function might be split across
+//! two chunks. Use a [`StreamParser`] to hold back incomplete control functions until the rest of them arrives.
+//!
+//! ```
+//! use ansi_control_codes::c1::NEL;
+//! use ansi_control_codes::parser::{StreamParser, Token};
+//!
+//! let mut parser = StreamParser::new();
+//! for chunk in [&b"Line 1\x1b"[..], &b"ELine 2"[..]] {
+//!     for token in parser.feed(chunk) {
+//!         println!("{:?}", token);
+//!     }
+//! }
+//! for token in parser.finish() {
+//!     println!("{:?}", token);
+//! }
+//! ```
+//!
+//! ## Parsing Control Strings
+//!
+//! Control strings, such as operating system commands, are emitted as a single [`Token::ControlString`] that contains
+//! the opening delimiter, the payload, and the terminating delimiter. Operating system commands that are terminated by
+//! BELL ([`BEL`]) instead of the STRING TERMINATOR ([`ST`]) are accepted as well, unless this is disabled with
+//! [`TokenStream::accept_bell_terminator`].
+//!
+//! ```
+//! use ansi_control_codes::c1::OSC;
+//! use ansi_control_codes::control_strings::{operating_system_command, Terminator};
+//! use ansi_control_codes::parser::{TokenStream, Token};
+//!
+//! let to_be_parsed = format!("{}Hello", operating_system_command("0;Window Title"));
+//! let parts: Vec<Token> = TokenStream::from(&to_be_parsed).collect();
+//!
+//! assert_eq!(
+//!     parts[0],
+//!     Token::ControlString {
+//!         opener: OSC,
+//!         payload: "0;Window Title",
+//!         terminator: Terminator::StringTerminator
+//!     }
+//! );
+//! assert_eq!(parts[1], Token::String("Hello"));
+//! ```
+//!
+//! ## Token Positions
+//!
+//! To point back at the part of the input that a token was parsed from, e.g. to highlight it, turn the token stream
+//! into an iterator over the tokens and their byte ranges with [`TokenStream::spanned`] or
+//! [`ByteTokenStream::spanned`].
+//!
+//! ```
+//! use ansi_control_codes::parser::{Token, TokenStream};
+//!
+//! let input = "Hello \x1b[1mWorld";
+//! for (range, token) in TokenStream::from(input).spanned() {
+//!     if let Token::ControlFunction(_) = token {
+//!         assert_eq!(&input[range], "\x1b[1m");
+//!     }
+//! }
+//! ```
+//!
+//! ## Parsing a Single Control Function
+//!
+//! A string that is expected to contain exactly one control function, e.g. one that is read from a configuration file,
+//! can be validated with [`ControlFunction::parse_one`], or with [`str::parse`]. Both reject text, control strings,
+//! malformed control sequences, and any input following the control function.
+//!
+//! ```
+//! use ansi_control_codes::ControlFunction;
+//! use ansi_control_codes::parser::ParseControlFunctionError;
+//!
+//! let cursor_shape: ControlFunction = "\x1b[2 q".parse().unwrap();
+//! assert_eq!(cursor_shape.final_byte(), b'q');
+//!
+//! let invalid = "\x1b[2 q\x1b[0 q".parse::<ControlFunction>();
+//! assert_eq!(invalid, Err(ParseControlFunctionError::TrailingInput(5)));
+//! ```
+//!
 //! ## Parse and Explain
 //!
 //! You can combine the features `parser` and `explain` of this crate to parse text and explain the meaning of
@@ -57,6 +159,8 @@
 //!             );
 //!             println!("Long description: {}", control_function.long_description());
 //!         }
+//!         // a TokenStream never emits Token::Bytes, and there are no other tokens in the example
+//!         _ => unreachable!(),
 //!     }
 //!     if i < (result.len() - 1) {
 //!         println!("---------------------");
@@ -79,23 +183,151 @@
 //! position according to the line progression, and to the 6 character position according to the character path.
 //! ```
 
-use crate::{c0::*, c1::*, independent_control_functions::*, ControlFunction};
+use std::{error::Error, fmt, ops::Range, str};
+
+use crate::{
+    c0::*, c1::*, control_strings::Terminator, independent_control_functions::*, ControlFunction,
+    Parameter, PrivateParameter,
+};
+
+/// All C0 Codes, indexed by their byte `00/00` to `01/15`.
+///
+/// All of them can be parsed without any lookahead, except for ESC, which might introduce a longer sequence.
+static C0_CODES: [ControlFunction; 32] = [
+    NUL, SOH, STX, ETX, EOT, ENQ, ACK, BEL, BS, HT, LF, VT, FF, CR, SO, SI, DLE, DC1, DC2, DC3,
+    DC4, NAK, SYN, ETB, CAN, EM, SUB, ESC, IS4, IS3, IS2, IS1,
+];
 
-/// All C0 Codes that can be parsed without any lookahead (all C0 codes except for ESC)
-const C0_CODES: [ControlFunction; 31] = [
-    ACK, BEL, BS, CAN, CR, DC1, DC2, DC3, DC4, DLE, EM, ENQ, EOT, ETB, ETX, FF, HT, IS1, IS2, IS3,
-    IS4, LF, NAK, NUL, SI, SO, SOH, STX, SUB, SYN, VT,
+/// All C1 Codes that can be parsed without any lookahead (all C1 codes except for CSI), indexed by the byte following
+/// ESC in their 7-bit representation, starting at `04/00`.
+static C1_CODES: [Option<ControlFunction>; 32] = [
+    None,
+    None,
+    Some(BPH),
+    Some(NBH),
+    None,
+    Some(NEL),
+    Some(SSA),
+    Some(ESA),
+    Some(HTS),
+    Some(HTJ),
+    Some(VTS),
+    Some(PLD),
+    Some(PLU),
+    Some(RI),
+    Some(SS2),
+    Some(SS3),
+    Some(DCS),
+    Some(PU1),
+    Some(PU2),
+    Some(STS),
+    Some(CCH),
+    Some(MW),
+    Some(SPA),
+    Some(EPA),
+    Some(SOS),
+    None,
+    Some(SCI),
+    None,
+    Some(ST),
+    Some(OSC),
+    Some(PM),
+    Some(APC),
 ];
 
-/// All C1 Codes that can be parsed without any lookahead (all C1 codes except for CSI)
-const C1_CODES: [ControlFunction; 27] = [
-    BPH, NBH, NEL, SSA, ESA, HTS, HTJ, VTS, PLD, PLU, RI, SS2, SS3, DCS, PU1, PU2, STS, CCH, MW,
-    SPA, EPA, SOS, SCI, ST, OSC, PM, APC,
+/// The byte following ESC in the 7-bit representation of the first C1 code (`04/00`).
+const C1_LOWER_BOUND: u8 = ascii!(04 / 00).as_bytes()[0];
+
+/// All independent control codes, indexed by the byte following ESC, starting at `06/00`.
+static INDEPENDENT_CODES: [Option<ControlFunction>; 32] = [
+    Some(DMI),
+    Some(INT),
+    Some(EMI),
+    Some(RIS),
+    Some(CMD),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(LS2),
+    Some(LS3),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(LS3R),
+    Some(LS2R),
+    Some(LS1R),
+    None,
 ];
 
-/// All independent control codes.
-const INDEPENDENT_CODES: [ControlFunction; 10] =
-    [DMI, INT, EMI, RIS, CMD, LS2, LS3, LS3R, LS2R, LS1R];
+/// The byte following ESC of the first independent control code (`06/00`).
+const INDEPENDENT_LOWER_BOUND: u8 = ascii!(06 / 00).as_bytes()[0];
+
+/// The class of a byte, which determines how the tokenizer treats it when it is not part of a longer sequence.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ByteClass {
+    /// A graphic character (`02/00` to `07/14`) or DELETE (`07/15`), which is always text.
+    Text,
+    /// A C0 control function other than ESC, which is identified by this single byte.
+    C0,
+    /// ESC, which might introduce a longer sequence.
+    Escape,
+    /// A byte outside of the ASCII range, which is text, unless it represents a C1 control function.
+    NonAscii,
+}
+
+/// The class of each byte, indexed by the byte.
+static BYTE_CLASSES: [ByteClass; 256] = byte_classes();
+
+/// Builds the table [`BYTE_CLASSES`].
+const fn byte_classes() -> [ByteClass; 256] {
+    let mut classes = [ByteClass::Text; 256];
+    let mut byte = 0;
+    while byte < classes.len() {
+        classes[byte] = if byte == ESCAPE_BYTE as usize {
+            ByteClass::Escape
+        } else if byte < INTERMEDIATE_LOWER_BOUND as usize {
+            ByteClass::C0
+        } else if byte > DELETE_BYTE as usize {
+            ByteClass::NonAscii
+        } else {
+            ByteClass::Text
+        };
+        byte += 1;
+    }
+    classes
+}
+
+/// The byte representing ESC (`01/11`), which might introduce longer sequences.
+const ESCAPE_BYTE: u8 = ascii!(01 / 11).as_bytes()[0];
+
+/// The byte of DELETE (`07/15`), the last byte of the ASCII range.
+const DELETE_BYTE: u8 = ascii!(07 / 15).as_bytes()[0];
+
+/// The byte following ESC to form the control sequence introducer CSI (`05/11`).
+const CSI_BYTE: u8 = ascii!(05 / 11).as_bytes()[0];
+
+/// Lower bound of valid intermediate bytes.
+/// Intermediate bytes can be between 02 / 00 and 02 / 15.
+const INTERMEDIATE_LOWER_BOUND: u8 = ascii!(02 / 00).as_bytes()[0];
+
+/// Upper bound of valid intermediate bytes.
+/// Intermediate bytes can be between 02 / 00 and 02 / 15.
+const INTERMEDIATE_UPPER_BOUND: u8 = ascii!(02 / 15).as_bytes()[0];
 
 /// Lower bound of valid characters for control function values.
 /// Control sequences end with characters between 04/00 and 06/15
@@ -115,335 +347,1377 @@ const PARAMETER_LOWER_BOUND: u8 = ascii!(03 / 00).as_bytes()[0];
 /// Parameter bytes can be between 03 / 00 and 03 / 15.
 const PARAMETER_UPPER_BOUND: u8 = ascii!(03 / 15).as_bytes()[0];
 
-/// Parameter separator byte.
-const PARAMETER_SEPARATOR: &str = ascii!(03 / 11);
+/// All C1 Codes that open a control string.
+static CONTROL_STRING_OPENERS: [ControlFunction; 5] = [APC, DCS, OSC, PM, SOS];
+
+/// The byte following ESC to form the STRING TERMINATOR ST (`05/12`).
+const ST_BYTE: u8 = ascii!(05 / 12).as_bytes()[0];
+
+/// The byte following ESC to form START OF STRING SOS (`05/08`).
+const SOS_BYTE: u8 = ascii!(05 / 08).as_bytes()[0];
+
+/// The byte of BELL BEL (`00/07`), which can terminate an operating system command.
+const BELL_BYTE: u8 = ascii!(00 / 07).as_bytes()[0];
+
+/// Lower bound of the 8-bit representation of C1 control functions (`08/00`).
+const C1_8BIT_LOWER_BOUND: u8 = 0x80;
+
+/// Upper bound of the 8-bit representation of C1 control functions (`09/15`).
+const C1_8BIT_UPPER_BOUND: u8 = 0x9F;
+
+/// The first byte of the UTF-8 encoding of the code points `U+0080` to `U+009F`, which represent C1 control functions.
+/// The second byte is the same as the 8-bit representation of the C1 control function.
+const C1_UNICODE_LEAD_BYTE: u8 = 0xC2;
+
+/// Difference between the 8-bit representation of a C1 control function and the byte following ESC in its 7-bit
+/// representation.
+const C1_8BIT_OFFSET: u8 = 0x40;
+
+/// Lower bound of the format effectors that are allowed inside of a command string.
+/// Command strings can contain bytes between 00/08 and 00/13, and between 02/00 and 07/14.
+const COMMAND_STRING_FORMAT_EFFECTOR_LOWER_BOUND: u8 = ascii!(00 / 08).as_bytes()[0];
+
+/// Upper bound of the format effectors that are allowed inside of a command string.
+/// Command strings can contain bytes between 00/08 and 00/13, and between 02/00 and 07/14.
+const COMMAND_STRING_FORMAT_EFFECTOR_UPPER_BOUND: u8 = ascii!(00 / 13).as_bytes()[0];
 
-/// A Token contains a part of the parsed string. Each part is either a String that does not contain any
-/// ansi-control-codes (represented by [`Token::String`]), or a ansi-control-code (represented by
-/// [`Token::ControlFunction`]).
+/// Lower bound of the graphic characters that are allowed inside of a command string.
+/// Command strings can contain bytes between 00/08 and 00/13, and between 02/00 and 07/14.
+const COMMAND_STRING_LOWER_BOUND: u8 = ascii!(02 / 00).as_bytes()[0];
+
+/// Upper bound of the graphic characters that are allowed inside of a command string.
+/// Command strings can contain bytes between 00/08 and 00/13, and between 02/00 and 07/14.
+const COMMAND_STRING_UPPER_BOUND: u8 = ascii!(07 / 14).as_bytes()[0];
+
+/// The number of bytes a [`StreamParser`] holds back by default, while waiting for the rest of a control function.
+const DEFAULT_MAX_PENDING_LENGTH: usize = 64 * 1024;
+
+/// A Token contains a part of the parsed input. Each part is either a String that does not contain any
+/// ansi-control-codes (represented by [`Token::String`], or [`Token::Bytes`] if the input was given as bytes), a
+/// ansi-control-code (represented by [`Token::ControlFunction`]), a complete control string (represented by
+/// [`Token::ControlString`], or [`Token::ByteControlString`] if the input was given as bytes), or a malformed control
+/// sequence (represented by [`Token::Malformed`]).
 ///
-/// A `Token` can be obtained by creating a [`TokenStream`] and iterating over it.
-#[derive(Debug, PartialEq, Eq)]
+/// A `Token` can be obtained by creating a [`TokenStream`] or a [`ByteTokenStream`] and iterating over it.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Token<'a> {
     /// A string slice that does not contain any valid ansi-control-code.
+    ///
+    /// This variant is emitted by the [`TokenStream`].
     String(&'a str),
+    /// A byte slice that does not contain any valid ansi-control-code.
+    ///
+    /// This variant is emitted by the [`ByteTokenStream`]. The bytes are not guaranteed to be valid UTF-8.
+    Bytes(&'a [u8]),
     /// A valid ansi-control-code that was found in the parsed string.
     ControlFunction(ControlFunction<'a>),
+    /// A control string that was found in the parsed string.
+    ///
+    /// A control string is opened by one of APPLICATION PROGRAM COMMAND ([`APC`]), DEVICE CONTROL STRING ([`DCS`]),
+    /// OPERATING SYSTEM COMMAND ([`OSC`]), PRIVACY MESSAGE ([`PM`]), or START OF STRING ([`SOS`]), and is closed by
+    /// the STRING TERMINATOR ([`ST`]). An operating system command can also be closed by BELL ([`BEL`]), unless this
+    /// was disabled in the parser.
+    ///
+    /// See also the module [`control_strings`][crate::control_strings].
+    ControlString {
+        /// The opening delimiter of the control string.
+        opener: ControlFunction<'a>,
+        /// The command string or character string between the delimiters.
+        payload: &'a str,
+        /// The terminating delimiter of the control string.
+        terminator: Terminator,
+    },
+    /// A control string that was found in the parsed bytes.
+    ///
+    /// This variant is emitted instead of [`Token::ControlString`] by the [`ByteTokenStream`] and the
+    /// [`StreamParser`]. The payload is not guaranteed to be valid UTF-8.
+    ByteControlString {
+        /// The opening delimiter of the control string.
+        opener: ControlFunction<'a>,
+        /// The command string or character string between the delimiters.
+        payload: &'a [u8],
+        /// The terminating delimiter of the control string.
+        terminator: Terminator,
+    },
+    /// A malformed control sequence that was found in the parsed string.
+    Malformed {
+        /// All bytes of the malformed control sequence, including the CONTROL SEQUENCE INTRODUCER ([`CSI`]).
+        bytes: &'a [u8],
+        /// The reason why the control sequence is malformed.
+        reason: MalformedReason,
+    },
 }
 
-/// A TokenStream is a stream of [`Token`]s that were parsed from an input string.
-/// The TokenStream implements the [`Iterator`] interface, which can be used to extract the result of a parse operation.
+/// The reason why a control sequence is malformed.
 ///
-/// The parse operation can never fail. If invalid ansi-control-codes are detected in the input string, they will be
-/// emitted as normal Strings ([`Token::String`]). Only valid ansi-control-codes will be emitted as ControlFunctions
-/// ([`Token::ControlFunction`]).
+/// See [`Token::Malformed`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MalformedReason {
+    /// The control sequence contains a byte that is not valid ASCII. The control sequence ends in front of this byte.
+    NonAsciiByte,
+    /// The control sequence is not terminated by a final byte. It either ends at the end of the input, or in front of
+    /// a control character.
+    Unterminated,
+    /// The control sequence contains a parameter byte after an intermediate byte.
+    ParameterAfterIntermediate,
+    /// The control sequence contains a parameter that does not consist of numeric values, separated by `03/10`, or a
+    /// numeric value that is too large.
+    InvalidParameter,
+}
+
+impl fmt::Display for MalformedReason {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MalformedReason::NonAsciiByte => {
+                write!(formatter, "Control sequence contains a non-ASCII byte")
+            }
+            MalformedReason::Unterminated => {
+                write!(formatter, "Control sequence is not terminated")
+            }
+            MalformedReason::ParameterAfterIntermediate => write!(
+                formatter,
+                "Control sequence contains a parameter after an intermediate byte"
+            ),
+            MalformedReason::InvalidParameter => {
+                write!(formatter, "Control sequence contains an invalid parameter")
+            }
+        }
+    }
+}
+
+impl<'a> Token<'a> {
+    /// Converts this token into an [`OwnedToken`], that does not borrow from the parsed input.
+    pub fn into_owned(self) -> OwnedToken {
+        match self {
+            Token::String(string) => OwnedToken::String(string.to_owned()),
+            Token::Bytes(bytes) => OwnedToken::Bytes(bytes.to_owned()),
+            Token::ControlFunction(control_function) => {
+                OwnedToken::ControlFunction(control_function.into_owned())
+            }
+            Token::ControlString {
+                opener,
+                payload,
+                terminator,
+            } => OwnedToken::ControlString {
+                opener: opener.into_owned(),
+                payload: payload.to_owned(),
+                terminator,
+            },
+            Token::ByteControlString {
+                opener,
+                payload,
+                terminator,
+            } => OwnedToken::ByteControlString {
+                opener: opener.into_owned(),
+                payload: payload.to_owned(),
+                terminator,
+            },
+            Token::Malformed { bytes, reason } => OwnedToken::Malformed {
+                bytes: bytes.to_owned(),
+                reason,
+            },
+        }
+    }
+}
+
+/// A [`Token`] that owns all of its data.
+///
+/// Tokens borrow from the parsed input, so they cannot outlive it. An owned token can be obtained with
+/// [`Token::into_owned`], and can be stored or sent to other threads independently of the input. Owned tokens can be
+/// compared with tokens using `==`, with the owned token on the left-hand side.
+///
+/// ```
+/// use ansi_control_codes::c0::BEL;
+/// use ansi_control_codes::parser::{OwnedToken, Token, TokenStream};
+///
+/// let input = String::from("Ring the bell\u{0007}");
+/// let history: Vec<OwnedToken> = TokenStream::from(&input).map(Token::into_owned).collect();
+/// drop(input);
+///
+/// assert_eq!(history[0], Token::String("Ring the bell"));
+/// assert_eq!(history[1], Token::ControlFunction(BEL));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum OwnedToken {
+    /// See [`Token::String`].
+    String(String),
+    /// See [`Token::Bytes`].
+    Bytes(Vec<u8>),
+    /// See [`Token::ControlFunction`].
+    ControlFunction(ControlFunction<'static>),
+    /// See [`Token::ControlString`].
+    ControlString {
+        /// The opening delimiter of the control string.
+        opener: ControlFunction<'static>,
+        /// The command string or character string between the delimiters.
+        payload: String,
+        /// The terminating delimiter of the control string.
+        terminator: Terminator,
+    },
+    /// See [`Token::ByteControlString`].
+    ByteControlString {
+        /// The opening delimiter of the control string.
+        opener: ControlFunction<'static>,
+        /// The command string or character string between the delimiters.
+        payload: Vec<u8>,
+        /// The terminating delimiter of the control string.
+        terminator: Terminator,
+    },
+    /// See [`Token::Malformed`].
+    Malformed {
+        /// All bytes of the malformed control sequence, including the CONTROL SEQUENCE INTRODUCER ([`CSI`]).
+        bytes: Vec<u8>,
+        /// The reason why the control sequence is malformed.
+        reason: MalformedReason,
+    },
+}
+
+impl<'a> From<Token<'a>> for OwnedToken {
+    fn from(token: Token<'a>) -> Self {
+        token.into_owned()
+    }
+}
+
+impl<'a> PartialEq<Token<'a>> for OwnedToken {
+    fn eq(&self, other: &Token<'a>) -> bool {
+        match (self, other) {
+            (OwnedToken::String(string), Token::String(other_string)) => string == other_string,
+            (OwnedToken::Bytes(bytes), Token::Bytes(other_bytes)) => bytes == other_bytes,
+            (
+                OwnedToken::ControlFunction(control_function),
+                Token::ControlFunction(other_control_function),
+            ) => control_function == other_control_function,
+            (
+                OwnedToken::ControlString {
+                    opener,
+                    payload,
+                    terminator,
+                },
+                Token::ControlString {
+                    opener: other_opener,
+                    payload: other_payload,
+                    terminator: other_terminator,
+                },
+            ) => {
+                opener == other_opener && payload == other_payload && terminator == other_terminator
+            }
+            (
+                OwnedToken::ByteControlString {
+                    opener,
+                    payload,
+                    terminator,
+                },
+                Token::ByteControlString {
+                    opener: other_opener,
+                    payload: other_payload,
+                    terminator: other_terminator,
+                },
+            ) => {
+                opener == other_opener && payload == other_payload && terminator == other_terminator
+            }
+            (
+                OwnedToken::Malformed { bytes, reason },
+                Token::Malformed {
+                    bytes: other_bytes,
+                    reason: other_reason,
+                },
+            ) => bytes == other_bytes && reason == other_reason,
+            _ => false,
+        }
+    }
+}
+
+/// A token as it is produced by the [`Tokenizer`].
+///
+/// Text and payloads of control strings are represented by their position in the input, so that the [`TokenStream`]
+/// and the [`ByteTokenStream`] can each hand them out in their own representation.
+enum RawToken<'a> {
+    /// The input in this range does not contain any valid ansi-control-code.
+    Text(Range<usize>),
+    /// A valid ansi-control-code.
+    ControlFunction(ControlFunction<'a>),
+    /// A complete control string.
+    ControlString {
+        opener: ControlFunction<'a>,
+        payload: Range<usize>,
+        terminator: Terminator,
+    },
+    /// A malformed control sequence.
+    Malformed(Range<usize>, MalformedReason),
+}
+
+/// The result of scanning the input for a sequence that spans multiple bytes.
+enum Scan<T> {
+    /// A valid sequence was found, the position after its last byte is included.
+    Found(T, usize),
+    /// The input does not contain a valid ansi-control-code at this position.
+    Invalid,
+    /// The input ended before the ansi-control-code was terminated. The range of the input that was scanned without
+    /// finding the end of the ansi-control-code is included, scanning can resume from there once more input arrives.
+    Incomplete(Range<usize>),
+}
+
+/// The state machine that finds ansi-control-codes in a sequence of bytes.
+///
+/// All ansi-control-codes are valid ASCII. Bytes outside of the ASCII range can never be part of an ansi-control-code,
+/// and are always treated as text. If the input is valid UTF-8, this means that the boundaries between text and
+/// ansi-control-codes always fall on character boundaries.
+///
+/// If the tokenizer is `partial`, more input might follow after the end of `value`. An ansi-control-code that is not
+/// yet terminated at the end of `value` is then not emitted at all, instead the tokenizer stops in front of it.
+///
+/// The payload of the control string starting at `scanned.start` has already been scanned up to `scanned.end` without
+/// finding its end, scanning this control string resumes from there.
 #[derive(Debug)]
-pub struct TokenStream<'a> {
-    value: &'a str,
+struct Tokenizer<'a> {
+    value: &'a [u8],
     position: usize,
     max_position: usize,
+    partial: bool,
+    scanned: Range<usize>,
+    settings: Settings,
 }
 
-impl<'a> TokenStream<'a> {
-    /// Parse the given string `value` into a [`TokenStream`].
-    ///
-    /// The [`TokenStream`] can be iterated over to inspect the result of the parse operation.
-    pub fn from(value: &'a str) -> Self {
-        TokenStream {
+/// The settings of the parser, that are shared between all the different token streams.
+#[derive(Debug, Clone, Copy)]
+struct Settings {
+    /// Whether an operating system command can be terminated by BEL.
+    bell_terminator: bool,
+
+    /// Whether C1 control functions can be represented by single bytes from `08/00` to `09/15`.
+    c1_8bit: bool,
+
+    /// Whether C1 control functions can be represented by the unicode code points `U+0080` to `U+009F`.
+    c1_unicode: bool,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Settings {
+            bell_terminator: true,
+            c1_8bit: false,
+            c1_unicode: false,
+        }
+    }
+}
+
+impl<'a> Tokenizer<'a> {
+    fn new(value: &'a [u8], settings: Settings) -> Self {
+        Tokenizer {
             value,
-            // invariant: position always points to a valid character boundary inside the string stored in value.
             position: 0,
             max_position: value.len(),
+            partial: false,
+            scanned: 0..0,
+            settings,
         }
     }
 
-    fn get_next_char_boundary(&self, position: usize) -> usize {
-        // invariant: position is a valid character boundary. Next character boundary is at least at position + 1
-        // no more boundaries can be discovered, if position >= self.value.len()
-        if position >= self.max_position {
-            return position;
+    fn new_partial(value: &'a [u8], settings: Settings) -> Self {
+        Tokenizer {
+            partial: true,
+            ..Tokenizer::new(value, settings)
         }
+    }
 
-        let mut next_boundary = position + 1;
-        while !self.value.is_char_boundary(next_boundary) {
-            next_boundary += 1
-        }
-        next_boundary
+    /// Returns the given range of the input as string slice.
+    ///
+    /// Must only be called for ranges that contain ASCII bytes exclusively.
+    fn ascii(&self, range: Range<usize>) -> &'a str {
+        str::from_utf8(&self.value[range]).expect("Control functions only consist of ASCII bytes.")
     }
 
-    fn emit_current_string(&mut self, position: usize) -> Option<Token<'a>> {
+    fn emit_current_text(&mut self, position: usize) -> Option<RawToken<'a>> {
         let mut emit_token = None;
         if position != self.position {
-            emit_token = Some(Token::String(&self.value[self.position..position]));
+            emit_token = Some(RawToken::Text(self.position..position));
 
             self.position = position;
         }
 
         emit_token
     }
-}
-impl<'a> Iterator for TokenStream<'a> {
-    type Item = Token<'a>;
 
-    fn next(&mut self) -> Option<Self::Item> {
-        let mut current_position = self.position;
-        while current_position < self.max_position {
-            let next_char_boundary = self.get_next_char_boundary(current_position);
+    fn emit_control_function(
+        &mut self,
+        position: usize,
+        next_position: usize,
+        control_function: ControlFunction<'a>,
+    ) -> Option<RawToken<'a>> {
+        self.emit(
+            position,
+            next_position,
+            RawToken::ControlFunction(control_function),
+        )
+    }
 
-            // invariant: self.value[self.position..next_char_boundary] is a valid sub-string of value, as
-            // current_position and next_char_boundary both point to valid character boundaries.
-            // invariant: self.value[current_position..next_char_boundary] is a valid character.
+    fn emit(
+        &mut self,
+        position: usize,
+        next_position: usize,
+        token: RawToken<'a>,
+    ) -> Option<RawToken<'a>> {
+        // detected an ansi-control-code. But there might be other data that we need to emit from previous iterations
+        // that detected text data.
+        self.emit_current_text(position).or_else(|| {
+            // there was no text to emit before the control function, so we can emit the control function instead.
+            // We need to change the position of the tokenizer first.
+            self.position = next_position;
+            Some(token)
+        })
+    }
 
-            let current_char = &self.value[current_position..next_char_boundary];
-            if !current_char.is_ascii() {
-                // all ansi-control-codes are valid ascii. Non-ascii characters can never be part of an
-                // ansi-control-code
-                current_position = next_char_boundary;
-                continue;
+    /// Tries to parse the C1 control function that is identified by the byte `byte` (the byte following ESC in its
+    /// 7-bit representation). The C1 control function starts at `start`, the input following it starts at `position`.
+    ///
+    /// Returns `None` if `byte` does not identify a C1 control function.
+    fn c1_control_function(
+        &self,
+        byte: u8,
+        start: usize,
+        position: usize,
+    ) -> Option<Scan<RawToken<'a>>> {
+        // If the character is CSI, it introduces a control sequence
+        if byte == CSI_BYTE {
+            return Some(match self.control_sequence(position) {
+                Scan::Found(Ok(control_sequence), end_position) => {
+                    Scan::Found(RawToken::ControlFunction(control_sequence), end_position)
+                }
+                Scan::Found(Err(reason), end_position) => Scan::Found(
+                    RawToken::Malformed(start..end_position, reason),
+                    end_position,
+                ),
+                Scan::Incomplete(scanned) => Scan::Incomplete(scanned),
+                Scan::Invalid => Scan::Invalid,
+            });
+        }
+
+        // All C1 control codes are 1 character long and can be identified directly, except for CSI which might
+        // introduce a longer sequence. All of those, except CSI, are stored in the table C1_CODES
+        let ansi_control_code = C1_CODES
+            .get(usize::from(byte.checked_sub(C1_LOWER_BOUND)?))?
+            .clone()?;
+
+        // Some C1 codes open a control string, which is emitted as a whole, if it is valid.
+        if CONTROL_STRING_OPENERS.contains(&ansi_control_code) {
+            match self.control_string(&ansi_control_code, position) {
+                Scan::Found((payload, terminator), end_position) => {
+                    return Some(Scan::Found(
+                        RawToken::ControlString {
+                            opener: ansi_control_code,
+                            payload,
+                            terminator,
+                        },
+                        end_position,
+                    ));
+                }
+                // the rest of the control string might still arrive.
+                Scan::Incomplete(scanned) if self.partial => {
+                    return Some(Scan::Incomplete(scanned))
+                }
+                // this is not a valid control string, the opening delimiter is emitted on its own.
+                Scan::Incomplete(_) | Scan::Invalid => {}
             }
+        }
+
+        Some(Scan::Found(
+            RawToken::ControlFunction(ansi_control_code),
+            position,
+        ))
+    }
 
-            // when encountering an ASCII character, this might be beginning, or part of an already started
-            // ansi-control-code. It also might just be a normal ascii character that has nothing to do with any
-            // ansi-control-code.
+    /// Tries to parse a control sequence, whose parameters start at `position`.
+    ///
+    /// A malformed control sequence is found as the reason why it is malformed. A malformed control sequence extends
+    /// to its final byte, or up to the first byte that cannot be part of a control sequence at all.
+    fn control_sequence(
+        &self,
+        position: usize,
+    ) -> Scan<Result<ControlFunction<'a>, MalformedReason>> {
+        // between the CSI and the function value are the parameters. To find the parameter list, we need to find
+        // the end of the control sequence. Possible end values of the sequence depend on the sequence type.
+        // Sequences can end with or without intermediate bytes, remember where the first intermediate byte is.
+        let mut intermediate_position = None;
+
+        // the first reason that makes this control sequence malformed, if any.
+        let mut malformed = None;
+
+        // a private parameter indicator might precede the parameters.
+        let private_parameter = self
+            .value
+            .get(position)
+            .and_then(|&byte| PrivateParameter::from_byte(byte));
+        let parameters_position = if private_parameter.is_some() {
+            position + 1
+        } else {
+            position
+        };
+
+        // try to find a function value between lower_bound and upper_bound
+        let mut current_position = position;
+        while current_position < self.max_position {
+            let current_byte = self.value[current_position];
 
-            // All c0 control codes are 1 character long and can be identified directly, except for ESC which might
-            // introduce a longer sequence. All of those, except ESC, are stored in the array C0_CODES
-            if let Some(ansi_control_code) = C0_CODES
-                .into_iter()
-                .find(|c0_code| c0_code == &current_char)
+            // does this end the control function?
+            if (CONTROL_FUNCTION_LOWER_BOUND..=CONTROL_FUNCTION_UPPER_BOUND).contains(&current_byte)
             {
-                // detected a C0 ansi-control-code. But there might be other data that we need to emit from
-                // previous iterations of this loop that detected string data.
-                return self.emit_current_string(current_position).or_else(|| {
-                    // there was no string to emit before the control function, so we can emit the control function
-                    // instead. We need to change the position of the iterator first.
-                    self.position = next_char_boundary;
-                    Some(Token::ControlFunction(ansi_control_code))
-                });
-            }
+                if let Some(reason) = malformed {
+                    return Scan::Found(Err(reason), current_position + 1);
+                }
+
+                // detected the end of a control function
+                let value_position = intermediate_position.unwrap_or(current_position);
+                let Some(parameters) =
+                    Parameter::parse_all(self.ascii(parameters_position..value_position))
+                else {
+                    return Scan::Found(
+                        Err(MalformedReason::InvalidParameter),
+                        current_position + 1,
+                    );
+                };
+
+                let mut control_sequence = ControlFunction::new_sequence(
+                    self.ascii(value_position..current_position + 1),
+                    parameters,
+                );
+                if let Some(private_parameter) = private_parameter {
+                    control_sequence = control_sequence.with_private_parameter(private_parameter);
+                }
 
-            // This is either ESC (maybe introducing a longer sequence of control codes), or a normal string
-            // ESC is a special scenario, as it might introduce longer escape sequences
-            if ESC == current_char {
-                // if we have reached the end of the string, the ESC cannot be part of a longer sequence
-                if self.max_position == next_char_boundary {
-                    // detected an ESC. But there might be other data that we need to emit from
-                    // previous iterations of this loop that detected string data.
-                    return self.emit_current_string(current_position).or_else(|| {
-                        // there was no string to emit before the control function, so we can emit the control function
-                        // instead. We need to change the position of the iterator first.
-                        self.position = next_char_boundary;
-                        Some(Token::ControlFunction(ESC))
-                    });
+                return Scan::Found(Ok(control_sequence), current_position + 1);
+            } else if (PARAMETER_LOWER_BOUND..=PARAMETER_UPPER_BOUND).contains(&current_byte) {
+                if intermediate_position.is_some() {
+                    // we have already seen an intermediate byte, parameters are not allowed anymore.
+                    malformed.get_or_insert(MalformedReason::ParameterAfterIntermediate);
                 }
+            } else if (INTERMEDIATE_LOWER_BOUND..=INTERMEDIATE_UPPER_BOUND).contains(&current_byte)
+            {
+                intermediate_position.get_or_insert(current_position);
+            } else {
+                // this byte can never be part of a control sequence, the control sequence ends in front of it.
+                let reason = if current_byte.is_ascii() {
+                    MalformedReason::Unterminated
+                } else {
+                    MalformedReason::NonAsciiByte
+                };
+                return Scan::Found(Err(malformed.unwrap_or(reason)), current_position);
+            }
+
+            current_position += 1;
+        }
+
+        // reached end of input before the control sequence was terminated. The rest of the control sequence might
+        // still arrive. Control sequences are short, they are scanned from their start again.
+        if self.partial {
+            return Scan::Incomplete(position..position);
+        }
+
+        Scan::Found(
+            Err(malformed.unwrap_or(MalformedReason::Unterminated)),
+            current_position,
+        )
+    }
 
-                // we need to look-ahead to find if this is part of a longer sequence
-                // possible next character is one of C1, independent control function, or CSI
-                let next_next_char_boundary = self.get_next_char_boundary(next_char_boundary);
-
-                // invariant: self.value[self.position..next_next_char_boundary] is a valid sub-string of value, as
-                // current_position and next_next_char_boundary both point to valid character boundaries.
-                // invariant: self.value[next_char_boundary..next_next_char_boundary] is a valid character.
-                // invariant: self.value[current_position..next_next_char_boundary] is a valid string.
-
-                let current_char = &self.value[next_char_boundary..next_next_char_boundary];
-                let control_sequence = &self.value[current_position..next_next_char_boundary];
-                if !current_char.is_ascii() {
-                    // C1, independent control function, and CSI are valid ascii characters. If we find a non-ascii
-                    // character. this cannot be a control character or sequence. This is a standalone ESC character.
-                    // Emit the ESC. But there might be other data that we need to emit from
-                    // previous iterations of this loop that detected string data.
-                    return self.emit_current_string(current_position).or_else(|| {
-                        // there was no string to emit before the control function, so we can emit the control function
-                        // instead. We need to change the position of the iterator first.
-                        self.position = next_char_boundary;
-                        Some(Token::ControlFunction(ESC))
-                    });
+    /// Tries to parse a control string opened by `opener`, whose payload starts at `position`.
+    ///
+    /// The payload of a control string opened by `SOS` is a character string, which can contain any bytes except for
+    /// `SOS` and `ST`. The payload of all other control strings is a command string, which can contain the format
+    /// effectors `00/08` to `00/13`, and the bytes `02/00` to `07/14`. Other than the standard requires, non-ascii
+    /// bytes are accepted in command strings as well, since terminals commonly accept UTF-8 text in them (e.g. in
+    /// window titles).
+    ///
+    /// An operating system command can also be terminated by BEL, if this is enabled in the settings.
+    fn control_string(
+        &self,
+        opener: &ControlFunction<'a>,
+        position: usize,
+    ) -> Scan<(Range<usize>, Terminator)> {
+        let character_string = *opener == SOS;
+        let bell_terminator = self.settings.bell_terminator && *opener == OSC;
+
+        // the payload might have been scanned before, when less input was available.
+        let resume_position = if self.scanned.start == position {
+            self.scanned.end
+        } else {
+            position
+        };
+
+        let mut current_position = resume_position;
+        while current_position < self.max_position {
+            let current_byte = self.value[current_position];
+
+            if current_byte == ESCAPE_BYTE {
+                let Some(&next_byte) = self.value.get(current_position + 1) else {
+                    // reached end of input, the next byte might still terminate the control string.
+                    break;
+                };
+
+                if next_byte == ST_BYTE {
+                    // detected the end of the control string
+                    return Scan::Found(
+                        (position..current_position, Terminator::StringTerminator),
+                        current_position + 2,
+                    );
                 }
 
-                // A ASCII character might be a continuation of a longer control sequence, or it might just be normal
-                // text. If it is a continuation of a control function, it needs to be one of the C1 codes, one of
-                // the independent control codes, or a CSI starting a control sequence.
-
-                // Handle C1 Codes
-                // All C1 control codes are 1 character long and can be identified directly, except for CSI which might
-                // introduce a longer sequence. All of those, except CSI, are stored in the array C1_CODES
-                if let Some(ansi_control_code) = C1_CODES
-                    .into_iter()
-                    .find(|c1_code| c1_code == &control_sequence)
-                {
-                    // detected a C1 ansi-control-code. But there might be other data that we need to emit from
-                    // previous iterations of this loop that detected string data.
-                    return self.emit_current_string(current_position).or_else(|| {
-                        // there was no string to emit before the control function, so we can emit the control function
-                        // instead. We need to change the position of the iterator first.
-                        self.position = next_next_char_boundary;
-                        Some(Token::ControlFunction(ansi_control_code))
-                    });
+                // a character string may contain other escape sequences, except for a nested SOS. A command string
+                // is interrupted by any other escape sequence, this is invalid!
+                if !character_string || next_byte == SOS_BYTE {
+                    return Scan::Invalid;
+                }
+            } else if let Some((byte, length)) = self.encoded_c1(current_position) {
+                // the same rules apply to C1 control functions in their 8-bit or unicode representation
+                if byte == ST_BYTE {
+                    return Scan::Found(
+                        (position..current_position, Terminator::StringTerminator),
+                        current_position + length,
+                    );
                 }
 
-                // Handle Independent Control Functions
-                // All Independent Control Functions are 1 character long, and can be identified directly.
-                // All Independent control functions are stored in the array INDEPENDENT_CODES
-                if let Some(ansi_control_code) = INDEPENDENT_CODES
-                    .into_iter()
-                    .find(|independent_code| independent_code == &control_sequence)
-                {
-                    // detected a C1 ansi-control-code. But there might be other data that we need to emit from
-                    // previous iterations of this loop that detected string data.
-                    return self.emit_current_string(current_position).or_else(|| {
-                        // there was no string to emit before the control function, so we can emit the control function
-                        // instead.
-                        self.position = next_next_char_boundary;
-                        Some(Token::ControlFunction(ansi_control_code))
-                    });
+                if !character_string || byte == SOS_BYTE {
+                    return Scan::Invalid;
                 }
 
-                // If the character is CSI, it introduces a control sequence
-                if control_sequence == CSI {
-                    // between the CSI character and the function value. To find the parameter list, we need to find
-                    // the end of the control sequence. Possible end values of the sequence depend on the sequence type.
-                    // Sequences can start with or without an intermediate byte.
+                current_position += length;
+                continue;
+            } else if bell_terminator && current_byte == BELL_BYTE {
+                // detected the end of the operating system command
+                return Scan::Found(
+                    (position..current_position, Terminator::Bell),
+                    current_position + 1,
+                );
+            } else if !character_string
+                && current_byte.is_ascii()
+                && !(COMMAND_STRING_FORMAT_EFFECTOR_LOWER_BOUND
+                    ..=COMMAND_STRING_FORMAT_EFFECTOR_UPPER_BOUND)
+                    .contains(&current_byte)
+                && !(COMMAND_STRING_LOWER_BOUND..=COMMAND_STRING_UPPER_BOUND)
+                    .contains(&current_byte)
+            {
+                // this byte is not allowed in a command string, this is invalid!
+                return Scan::Invalid;
+            }
+
+            current_position += 1;
+        }
+
+        // reached end of input before the control string was terminated. The last byte might be the first byte of a
+        // terminator, it needs to be scanned again once more input arrives.
+        Scan::Incomplete(position..current_position.saturating_sub(1).max(resume_position))
+    }
+
+    /// Detects a C1 control function at `position` in its 8-bit representation, or in its representation as unicode
+    /// code point, if these representations are enabled in the settings.
+    ///
+    /// Returns the byte following ESC in the 7-bit representation of the C1 control function, and the number of bytes
+    /// used by the detected representation.
+    fn encoded_c1(&self, position: usize) -> Option<(u8, usize)> {
+        let current_byte = self.value[position];
+
+        if self.settings.c1_8bit
+            && (C1_8BIT_LOWER_BOUND..=C1_8BIT_UPPER_BOUND).contains(&current_byte)
+        {
+            return Some((current_byte - C1_8BIT_OFFSET, 1));
+        }
 
-                    let control_sequence_position = next_next_char_boundary;
+        if self.settings.c1_unicode && current_byte == C1_UNICODE_LEAD_BYTE {
+            let next_byte = *self.value.get(position + 1)?;
+            if (C1_8BIT_LOWER_BOUND..=C1_8BIT_UPPER_BOUND).contains(&next_byte) {
+                return Some((next_byte - C1_8BIT_OFFSET, 2));
+            }
+        }
 
-                    let lower_bound = CONTROL_FUNCTION_LOWER_BOUND;
-                    let upper_bound = CONTROL_FUNCTION_UPPER_BOUND;
-                    let parameter_lower_bound = PARAMETER_LOWER_BOUND;
-                    let parameter_upper_bound = PARAMETER_UPPER_BOUND;
+        None
+    }
+}
 
-                    let mut intermediate_byte = false;
+impl<'a> Iterator for Tokenizer<'a> {
+    type Item = RawToken<'a>;
 
-                    // try to find a function value between lower_bound and upper_bound
-                    let mut current_position_cs = control_sequence_position;
-                    let mut next_position_cs =
-                        self.get_next_char_boundary(control_sequence_position);
-                    'control_sequence_loop: loop {
-                        let current_char = &self.value[current_position_cs..next_position_cs];
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut current_position = self.position;
+        while current_position < self.max_position {
+            let next_position = current_position + 1;
+            let current_byte = self.value[current_position];
+
+            match BYTE_CLASSES[usize::from(current_byte)] {
+                // graphic characters are always text, they can never start an ansi-control-code.
+                ByteClass::Text => {}
+
+                // All c0 control codes are 1 character long and can be identified directly, except for ESC which
+                // might introduce a longer sequence. All of those are stored in the table C0_CODES
+                ByteClass::C0 => {
+                    return self.emit_control_function(
+                        current_position,
+                        next_position,
+                        C0_CODES[usize::from(current_byte)].clone(),
+                    );
+                }
 
-                        // non-ascii (multi-byte) values are never valid parameters to a control sequence, this is
-                        // invalid!
-                        if current_char.as_bytes().len() != 1 {
-                            break 'control_sequence_loop;
+                // ESC is a special scenario, as it might introduce longer escape sequences
+                ByteClass::Escape => {
+                    // if we have reached the end of the input, the ESC cannot be part of a longer sequence, unless
+                    // more input might follow.
+                    if self.max_position == next_position {
+                        if self.partial {
+                            return self.emit_current_text(current_position);
                         }
+                        return self.emit_control_function(current_position, next_position, ESC);
+                    }
 
-                        // does this end the control function?
-                        if current_char.as_bytes()[0] >= lower_bound
-                            && current_char.as_bytes()[0] <= upper_bound
-                        {
-                            // detected the end of a control function
-                            let control_function_value = if intermediate_byte {
-                                &self.value[current_position_cs - 1..next_position_cs]
-                            } else {
-                                current_char
-                            };
-                            let parameters_unparsed = if intermediate_byte {
-                                &self.value[control_sequence_position..(current_position_cs - 1)]
-                            } else {
-                                &self.value[control_sequence_position..current_position_cs]
-                            };
-                            let parameters = parameters_unparsed
-                                .split(PARAMETER_SEPARATOR)
-                                .map(String::from)
-                                .collect();
-
-                            // emit string token (if any) or the control function
-                            return self.emit_current_string(current_position).or_else(|| {
-                                // there was no string to emit before the control function, so we can emit the control
-                                // function instead.
-                                self.position = next_position_cs;
-                                Some(Token::ControlFunction(ControlFunction::new_sequence(
-                                    control_function_value,
-                                    parameters,
-                                )))
-                            });
-                        } else if intermediate_byte {
-                            // we have already seen an intermediate byte, but now the control function is still
-                            // not terminated. This is invalid!
-                            break 'control_sequence_loop;
-                        } else if current_char.as_bytes()[0] < parameter_lower_bound
-                            || current_char.as_bytes()[0] > parameter_upper_bound
-                        {
-                            // this is not a valid function value, and not a valid parameter byte
-                            // if it is not the intermediate byte, this is invalid!
-                            intermediate_byte = current_char == ascii!(02 / 00);
-                            if !intermediate_byte {
-                                break 'control_sequence_loop;
+                    // we need to look-ahead to find if this is part of a longer sequence
+                    // possible next character is one of C1, independent control function, or CSI
+                    let next_byte = self.value[next_position];
+                    let next_next_position = next_position + 1;
+
+                    // A ASCII character might be a continuation of a longer control sequence, or it might just be
+                    // normal text. If it is a continuation of a control function, it needs to be one of the C1 codes,
+                    // one of the independent control codes, or a CSI starting a control sequence. If we find a
+                    // non-ascii character, this cannot be a control character or sequence. This is a standalone ESC
+                    // character.
+                    match self.c1_control_function(next_byte, current_position, next_next_position)
+                    {
+                        Some(Scan::Found(token, end_position)) => {
+                            return self.emit(current_position, end_position, token);
+                        }
+                        // the rest of the control function might still arrive, stop in front of it.
+                        Some(Scan::Incomplete(scanned)) => {
+                            self.scanned = scanned;
+                            return self.emit_current_text(current_position);
+                        }
+                        // this is not a valid control function, the ESC is treated as normal text.
+                        Some(Scan::Invalid) => {}
+                        None => {
+                            // Handle Independent Control Functions
+                            // All Independent Control Functions are 1 character long, and can be identified
+                            // directly. All Independent control functions are stored in the table INDEPENDENT_CODES
+                            if let Some(ansi_control_code) = next_byte
+                                .checked_sub(INDEPENDENT_LOWER_BOUND)
+                                .and_then(|index| INDEPENDENT_CODES.get(usize::from(index)))
+                                .and_then(Option::as_ref)
+                            {
+                                return self.emit_control_function(
+                                    current_position,
+                                    next_next_position,
+                                    ansi_control_code.clone(),
+                                );
                             }
+
+                            // found ESC that did not introduce a longer sequence, emit as-is.
+                            return self.emit_control_function(
+                                current_position,
+                                next_position,
+                                ESC,
+                            );
                         }
+                    }
+                }
 
-                        // end of string reached?
-                        // this does not end the control function
-                        // check the next character (or exit, if there are no more characters).
-                        if next_position_cs == self.max_position {
-                            // nothing else to do any more, reached end of string, this can't be valid
-                            // since there was no valid end to this control sequence.
-                            break 'control_sequence_loop;
+                // all ansi-control-codes are valid ascii. Non-ascii bytes can never be part of an ansi-control-code,
+                // unless they represent a C1 control function by a single byte, or by a unicode code point.
+                ByteClass::NonAscii => {
+                    if let Some((byte, length)) = self.encoded_c1(current_position) {
+                        match self.c1_control_function(
+                            byte,
+                            current_position,
+                            current_position + length,
+                        ) {
+                            Some(Scan::Found(token, end_position)) => {
+                                return self.emit(current_position, end_position, token);
+                            }
+                            // the rest of the control function might still arrive, stop in front of it.
+                            Some(Scan::Incomplete(scanned)) => {
+                                self.scanned = scanned;
+                                return self.emit_current_text(current_position);
+                            }
+                            // this is not a valid control function, the bytes are treated as normal text.
+                            Some(Scan::Invalid) | None => {}
                         }
-                        current_position_cs = next_position_cs;
-                        next_position_cs = self.get_next_char_boundary(current_position_cs);
+                    } else if self.partial
+                        && self.settings.c1_unicode
+                        && current_byte == C1_UNICODE_LEAD_BYTE
+                        && next_position == self.max_position
+                    {
+                        // the next byte might still complete a C1 control function, stop in front of it.
+                        return self.emit_current_text(current_position);
                     }
-                } else {
-                    // found ESC that did not introduce a longer sequence, emit as-is.
-                    return self.emit_current_string(current_position).or_else(|| {
-                        // there was no string to emit before the control function, so we can emit the control function
-                        // instead.
-                        self.position = next_char_boundary;
-                        Some(Token::ControlFunction(ESC))
-                    });
                 }
             }
 
-            current_position = next_char_boundary;
+            current_position = next_position;
         }
 
-        // reached end of the input string.
+        // reached end of the input.
         // emit the last token, if there is still some parts of the input that have not been emitted yet.
-        self.emit_current_string(current_position)
+        self.emit_current_text(current_position)
     }
 }
 
-#[cfg(test)]
-mod tests {
-
-    use crate::{
-        c0::{BEL, CR, ESC, LF},
-        c1::{BPH, CSI, NBH, SOS},
-        control_sequences::{
-            DeviceAttributes, PrintQuality, ReversedString, TabulationControl, CHA, CHT, CTC, CUP,
-            DA, SPQR, SRS, SSW, SU, TCC,
-        },
-        independent_control_functions::{DMI, EMI, RIS},
-        ControlFunction,
-    };
-
-    use super::{Token, TokenStream};
+/// A TokenStream is a stream of [`Token`]s that were parsed from an input string.
+/// The TokenStream implements the [`Iterator`] interface, which can be used to extract the result of a parse operation.
+///
+/// The parse operation can never fail. If invalid ansi-control-codes are detected in the input string, they will be
+/// emitted as normal Strings ([`Token::String`]). Only valid ansi-control-codes will be emitted as ControlFunctions
+/// ([`Token::ControlFunction`]). Malformed control sequences are emitted as [`Token::Malformed`].
+#[derive(Debug)]
+pub struct TokenStream<'a> {
+    value: &'a str,
+    tokenizer: Tokenizer<'a>,
+}
 
-    #[test]
-    fn test_simple_ascii_string() {
-        let simple_ascii_input = "Hello World";
-        let mut token_stream = TokenStream::from(simple_ascii_input);
+impl<'a> TokenStream<'a> {
+    /// Parse the given string `value` into a [`TokenStream`].
+    ///
+    /// The [`TokenStream`] can be iterated over to inspect the result of the parse operation.
+    pub fn from(value: &'a str) -> Self {
+        TokenStream {
+            value,
+            tokenizer: Tokenizer::new(value.as_bytes(), Settings::default()),
+        }
+    }
 
-        let first_element = token_stream.next();
-        let second_element = token_stream.next();
+    /// Configure whether an operating system command ([`OSC`]) can be terminated by BELL ([`BEL`]).
+    ///
+    /// This is enabled by default, since most terminals send and accept [`BEL`] as terminator. ECMA-48 only specifies
+    /// the STRING TERMINATOR ([`ST`]), disable this setting to parse control strings strictly according to ECMA-48.
+    pub fn accept_bell_terminator(mut self, accept: bool) -> Self {
+        self.tokenizer.settings.bell_terminator = accept;
+        self
+    }
 
-        assert!(first_element.is_some_and(|value| value == Token::String(simple_ascii_input)));
-        assert!(second_element.is_none());
+    /// Configure whether C1 control functions are recognized in their representation as unicode code points.
+    ///
+    /// The unicode code points `U+0080` to `U+009F` correspond to the 8-bit representation of C1 control functions.
+    /// For example, the code point `U+009B` represents a CONTROL SEQUENCE INTRODUCER ([`CSI`]). This is disabled by
+    /// default, the code points are then treated as normal text.
+    pub fn accept_unicode_c1(mut self, accept: bool) -> Self {
+        self.tokenizer.settings.c1_unicode = accept;
+        self
     }
 
-    #[test]
-    fn test_simple_non_ascii_string() {
-        let simple_non_ascii_input = "Löwe 老虎 Léopard";
-        let mut token_stream = TokenStream::from(simple_non_ascii_input);
+    /// Turns this [`TokenStream`] into an iterator over the tokens and the byte ranges of the input that they were
+    /// parsed from.
+    ///
+    /// ```
+    /// use ansi_control_codes::c0::BEL;
+    /// use ansi_control_codes::parser::{Token, TokenStream};
+    ///
+    /// let spans: Vec<_> = TokenStream::from("Ring\x07").spanned().collect();
+    /// assert_eq!(spans, vec![(0..4, Token::String("Ring")), (4..5, Token::ControlFunction(BEL))]);
+    /// ```
+    pub fn spanned(self) -> Spanned<Self> {
+        Spanned { token_stream: self }
+    }
+}
 
-        let first_element = token_stream.next();
-        let second_element = token_stream.next();
+impl<'a> Iterator for TokenStream<'a> {
+    type Item = Token<'a>;
 
-        assert!(first_element.is_some_and(|value| value == Token::String(simple_non_ascii_input)));
-        assert!(second_element.is_none());
+    fn next(&mut self) -> Option<Self::Item> {
+        self.tokenizer.next().map(|token| match token {
+            // invariant: text ranges always start and end at the borders of the input or at the borders of an
+            // ansi-control-code, which are valid character boundaries.
+            RawToken::Text(range) => Token::String(&self.value[range]),
+            RawToken::ControlFunction(control_function) => Token::ControlFunction(control_function),
+            // invariant: the payload of a control string is delimited by ansi-control-codes, its borders are valid
+            // character boundaries as well.
+            RawToken::ControlString {
+                opener,
+                payload,
+                terminator,
+            } => Token::ControlString {
+                opener,
+                payload: &self.value[payload],
+                terminator,
+            },
+            RawToken::Malformed(range, reason) => Token::Malformed {
+                bytes: &self.value.as_bytes()[range],
+                reason,
+            },
+        })
     }
+}
 
-    #[test]
+impl<'a> ControlFunction<'a> {
+    /// Parses a string that contains exactly one control function.
+    ///
+    /// The string is parsed by a [`TokenStream`] with its default settings. Parsing fails if the string is empty, if it
+    /// does not start with a control function, or if there is more input after the control function.
+    ///
+    /// The parsed control function borrows from the input. To obtain a control function that does not, use
+    /// [`str::parse`] instead.
+    ///
+    /// ```
+    /// use ansi_control_codes::control_sequences::CUP;
+    /// use ansi_control_codes::parser::ParseControlFunctionError;
+    /// use ansi_control_codes::ControlFunction;
+    ///
+    /// assert_eq!(ControlFunction::parse_one("\x1b[5;13H"), Ok(CUP(Some(5), Some(13))));
+    /// assert_eq!(
+    ///     ControlFunction::parse_one("\x1b[5;13HHello"),
+    ///     Err(ParseControlFunctionError::TrailingInput(7))
+    /// );
+    ///
+    /// let cursor_position: ControlFunction = "\x1b[5;13H".parse().unwrap();
+    /// assert_eq!(cursor_position, CUP(Some(5), Some(13)));
+    /// ```
+    pub fn parse_one(value: &'a str) -> Result<Self, ParseControlFunctionError> {
+        let mut token_stream = TokenStream::from(value);
+        let control_function = match token_stream.next() {
+            Some(Token::ControlFunction(control_function)) => control_function,
+            Some(Token::ControlString { .. }) | Some(Token::ByteControlString { .. }) => {
+                return Err(ParseControlFunctionError::ControlString)
+            }
+            Some(Token::Malformed { reason, .. }) => {
+                return Err(ParseControlFunctionError::Malformed(reason))
+            }
+            Some(Token::String(_)) | Some(Token::Bytes(_)) => {
+                return Err(ParseControlFunctionError::NotAControlFunction)
+            }
+            None => return Err(ParseControlFunctionError::Empty),
+        };
+
+        match token_stream.tokenizer.position {
+            position if position == value.len() => Ok(control_function),
+            position => Err(ParseControlFunctionError::TrailingInput(position)),
+        }
+    }
+}
+
+impl str::FromStr for ControlFunction<'static> {
+    type Err = ParseControlFunctionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ControlFunction::parse_one(s).map(ControlFunction::into_owned)
+    }
+}
+
+/// The reason why a string could not be parsed into exactly one [`ControlFunction`].
+///
+/// See [`ControlFunction::parse_one`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseControlFunctionError {
+    /// The string is empty.
+    Empty,
+    /// The string does not start with a control function, but with text.
+    NotAControlFunction,
+    /// The string starts with a control string, which consists of more than one control function.
+    ControlString,
+    /// The string starts with a malformed control sequence.
+    Malformed(MalformedReason),
+    /// The string continues after the control function. The byte offset of the remaining input is included.
+    TrailingInput(usize),
+}
+
+impl fmt::Display for ParseControlFunctionError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseControlFunctionError::Empty => {
+                write!(
+                    formatter,
+                    "Expected a control function, found an empty string"
+                )
+            }
+            ParseControlFunctionError::NotAControlFunction => {
+                write!(formatter, "Expected a control function, found text")
+            }
+            ParseControlFunctionError::ControlString => {
+                write!(
+                    formatter,
+                    "Expected a control function, found a control string"
+                )
+            }
+            ParseControlFunctionError::Malformed(reason) => write!(formatter, "{}", reason),
+            ParseControlFunctionError::TrailingInput(position) => write!(
+                formatter,
+                "Expected a single control function, found more input at byte {}",
+                position
+            ),
+        }
+    }
+}
+
+impl Error for ParseControlFunctionError {}
+
+/// A ByteTokenStream is a stream of [`Token`]s that were parsed from a slice of bytes.
+/// The ByteTokenStream implements the [`Iterator`] interface, which can be used to extract the result of a parse
+/// operation.
+///
+/// Other than the [`TokenStream`], the input does not need to be valid UTF-8. This is useful to parse data that is
+/// read from a pty or a serial line, where chunks of data might end in the middle of a multi-byte character. All text
+/// is emitted as [`Token::Bytes`], and is never validated.
+///
+/// The parse operation can never fail. If invalid ansi-control-codes are detected in the input, they will be
+/// emitted as normal bytes ([`Token::Bytes`]). Only valid ansi-control-codes will be emitted as ControlFunctions
+/// ([`Token::ControlFunction`]). Malformed control sequences are emitted as [`Token::Malformed`]. Control strings are
+/// emitted as [`Token::ByteControlString`], their payload is not validated either.
+#[derive(Debug)]
+pub struct ByteTokenStream<'a> {
+    value: &'a [u8],
+    tokenizer: Tokenizer<'a>,
+}
+
+impl<'a> ByteTokenStream<'a> {
+    /// Parse the given bytes `value` into a [`ByteTokenStream`].
+    ///
+    /// The [`ByteTokenStream`] can be iterated over to inspect the result of the parse operation.
+    pub fn from(value: &'a [u8]) -> Self {
+        ByteTokenStream {
+            value,
+            tokenizer: Tokenizer::new(value, Settings::default()),
+        }
+    }
+
+    /// Configure whether an operating system command ([`OSC`]) can be terminated by BELL ([`BEL`]).
+    ///
+    /// This is enabled by default, since most terminals send and accept [`BEL`] as terminator. ECMA-48 only specifies
+    /// the STRING TERMINATOR ([`ST`]), disable this setting to parse control strings strictly according to ECMA-48.
+    pub fn accept_bell_terminator(mut self, accept: bool) -> Self {
+        self.tokenizer.settings.bell_terminator = accept;
+        self
+    }
+
+    /// Configure whether C1 control functions are recognized in their 8-bit representation.
+    ///
+    /// ECMA-48 allows to represent C1 control functions by single bytes from `08/00` to `09/15`, instead of the 7-bit
+    /// escape sequence `ESC Fe`. For example, the byte `09/11` represents a CONTROL SEQUENCE INTRODUCER ([`CSI`]).
+    /// These bytes are also used in multi-byte UTF-8 characters, therefore this is disabled by default. Only enable
+    /// this setting if the input uses an 8-bit encoding.
+    pub fn accept_8bit_c1(mut self, accept: bool) -> Self {
+        self.tokenizer.settings.c1_8bit = accept;
+        self
+    }
+
+    /// Configure whether C1 control functions are recognized in their representation as unicode code points.
+    ///
+    /// The unicode code points `U+0080` to `U+009F` correspond to the 8-bit representation of C1 control functions.
+    /// For example, the code point `U+009B` represents a CONTROL SEQUENCE INTRODUCER ([`CSI`]). This is disabled by
+    /// default, the code points are then treated as normal text.
+    pub fn accept_unicode_c1(mut self, accept: bool) -> Self {
+        self.tokenizer.settings.c1_unicode = accept;
+        self
+    }
+
+    /// Turns this [`ByteTokenStream`] into an iterator over the tokens and the byte ranges of the input that they were
+    /// parsed from.
+    pub fn spanned(self) -> Spanned<Self> {
+        Spanned { token_stream: self }
+    }
+}
+
+impl<'a> Iterator for ByteTokenStream<'a> {
+    type Item = Token<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.tokenizer.next().map(|token| match token {
+            RawToken::Text(range) => Token::Bytes(&self.value[range]),
+            RawToken::ControlFunction(control_function) => Token::ControlFunction(control_function),
+            RawToken::ControlString {
+                opener,
+                payload,
+                terminator,
+            } => Token::ByteControlString {
+                opener,
+                payload: &self.value[payload],
+                terminator,
+            },
+            RawToken::Malformed(range, reason) => Token::Malformed {
+                bytes: &self.value[range],
+                reason,
+            },
+        })
+    }
+}
+
+/// An iterator over the [`Token`]s of a [`TokenStream`] or a [`ByteTokenStream`], together with the range of bytes in
+/// the input that each token was parsed from.
+///
+/// The ranges of consecutive tokens are adjacent, and together they cover the entire input. A `Spanned` iterator is
+/// created with [`TokenStream::spanned`] or [`ByteTokenStream::spanned`].
+#[derive(Debug)]
+pub struct Spanned<T> {
+    token_stream: T,
+}
+
+impl<'a> Iterator for Spanned<TokenStream<'a>> {
+    type Item = (Range<usize>, Token<'a>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let start = self.token_stream.tokenizer.position;
+        let token = self.token_stream.next()?;
+        Some((start..self.token_stream.tokenizer.position, token))
+    }
+}
+
+impl<'a> Iterator for Spanned<ByteTokenStream<'a>> {
+    type Item = (Range<usize>, Token<'a>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let start = self.token_stream.tokenizer.position;
+        let token = self.token_stream.next()?;
+        Some((start..self.token_stream.tokenizer.position, token))
+    }
+}
+
+/// A StreamParser parses ansi-control-codes from input that arrives in chunks, e.g. from consecutive calls to `read()`.
+///
+/// Chunks of input are pushed into the parser with [`feed`][StreamParser::feed]. A control function that is split
+/// across two chunks is held back by the parser until the next chunk arrives, instead of being emitted as text. Only
+/// an explicit call to [`finish`][StreamParser::finish] marks the end of the input, at which point anything that is
+/// still held back is emitted in the same way a [`ByteTokenStream`] would.
+///
+/// All text is emitted as [`Token::Bytes`], and all control strings as [`Token::ByteControlString`].
+///
+/// ```
+/// use ansi_control_codes::control_sequences::CUP;
+/// use ansi_control_codes::parser::{StreamParser, Token};
+///
+/// let mut parser = StreamParser::new();
+///
+/// let first: Vec<Token> = parser.feed(b"Hello\x1b[23;").collect();
+/// assert_eq!(first, vec![Token::Bytes(b"Hello")]);
+///
+/// let second: Vec<Token> = parser.feed(b"6HWorld").collect();
+/// assert_eq!(
+///     second,
+///     vec![
+///         Token::ControlFunction(CUP(23.into(), 6.into())),
+///         Token::Bytes(b"World")
+///     ]
+/// );
+///
+/// assert_eq!(parser.finish().next(), None);
+/// ```
+#[derive(Debug)]
+pub struct StreamParser {
+    buffer: Vec<u8>,
+    consumed: usize,
+    scanned: Range<usize>,
+    max_pending_length: usize,
+    settings: Settings,
+}
+
+impl Default for StreamParser {
+    fn default() -> Self {
+        StreamParser {
+            buffer: Vec::new(),
+            consumed: 0,
+            scanned: 0..0,
+            max_pending_length: DEFAULT_MAX_PENDING_LENGTH,
+            settings: Settings::default(),
+        }
+    }
+}
+
+impl StreamParser {
+    /// Creates a new [`StreamParser`] with no buffered input.
+    pub fn new() -> Self {
+        StreamParser::default()
+    }
+
+    /// Configure whether an operating system command ([`OSC`]) can be terminated by BELL ([`BEL`]).
+    ///
+    /// This is enabled by default, since most terminals send and accept [`BEL`] as terminator. ECMA-48 only specifies
+    /// the STRING TERMINATOR ([`ST`]), disable this setting to parse control strings strictly according to ECMA-48.
+    pub fn accept_bell_terminator(mut self, accept: bool) -> Self {
+        self.settings.bell_terminator = accept;
+        self
+    }
+
+    /// Configure whether C1 control functions are recognized in their 8-bit representation.
+    ///
+    /// ECMA-48 allows to represent C1 control functions by single bytes from `08/00` to `09/15`, instead of the 7-bit
+    /// escape sequence `ESC Fe`. For example, the byte `09/11` represents a CONTROL SEQUENCE INTRODUCER ([`CSI`]).
+    /// These bytes are also used in multi-byte UTF-8 characters, therefore this is disabled by default. Only enable
+    /// this setting if the input uses an 8-bit encoding.
+    pub fn accept_8bit_c1(mut self, accept: bool) -> Self {
+        self.settings.c1_8bit = accept;
+        self
+    }
+
+    /// Configure whether C1 control functions are recognized in their representation as unicode code points.
+    ///
+    /// The unicode code points `U+0080` to `U+009F` correspond to the 8-bit representation of C1 control functions.
+    /// For example, the code point `U+009B` represents a CONTROL SEQUENCE INTRODUCER ([`CSI`]). This is disabled by
+    /// default, the code points are then treated as normal text.
+    pub fn accept_unicode_c1(mut self, accept: bool) -> Self {
+        self.settings.c1_unicode = accept;
+        self
+    }
+
+    /// Configure how many bytes of an incomplete control function the parser holds back at most.
+    ///
+    /// A control string, e.g. an operating system command ([`OSC`]), can be arbitrarily long. If the terminator never
+    /// arrives, the parser would hold back all of the following input. Once more than `length` bytes are held back,
+    /// they are emitted in the same way as by [`finish`][StreamParser::finish], e.g. as [`Token::Malformed`], or as
+    /// the opening delimiter of the control string followed by text. The default is 64 KiB.
+    pub fn max_pending_length(mut self, length: usize) -> Self {
+        self.max_pending_length = length;
+        self
+    }
+
+    /// Push the next chunk of input `value` into the parser.
+    ///
+    /// Returns the [`Token`]s that can be parsed from the input received so far. If the input ends in the middle of a
+    /// control function, that control function is held back until more input is fed into the parser, or until the
+    /// parser is [finished][StreamParser::finish].
+    ///
+    /// Tokens that are not taken from the returned iterator stay in the parser, and are returned again by the next
+    /// call to `feed` or `finish`.
+    pub fn feed(&mut self, value: &[u8]) -> StreamTokens<'_> {
+        self.discard_consumed();
+        self.buffer.extend_from_slice(value);
+
+        let mut tokenizer = Tokenizer::new_partial(&self.buffer, self.settings);
+        tokenizer.scanned = self.scanned.clone();
+
+        StreamTokens {
+            value: &self.buffer,
+            tokenizer,
+            consumed: &mut self.consumed,
+            scanned: &mut self.scanned,
+            max_pending_length: self.max_pending_length,
+        }
+    }
+
+    /// Mark the end of the input.
+    ///
+    /// Returns the [`Token`]s for all the input that is still held back by the parser. A control function that was not
+    /// terminated is treated in the same way as by a [`ByteTokenStream`]. Once all tokens have been taken from the
+    /// returned iterator, the parser can be reused for new input.
+    pub fn finish(&mut self) -> StreamTokens<'_> {
+        self.discard_consumed();
+
+        let mut tokenizer = Tokenizer::new(&self.buffer, self.settings);
+        tokenizer.scanned = self.scanned.clone();
+
+        StreamTokens {
+            value: &self.buffer,
+            tokenizer,
+            consumed: &mut self.consumed,
+            scanned: &mut self.scanned,
+            max_pending_length: self.max_pending_length,
+        }
+    }
+
+    fn discard_consumed(&mut self) {
+        self.buffer.drain(..self.consumed);
+
+        // the positions of an incomplete control string move along with the input. The payload of a control string
+        // always starts after its opening delimiter, if it starts at or before the discarded input, the control string
+        // was emitted already.
+        self.scanned = if self.scanned.start > self.consumed {
+            self.scanned.start - self.consumed..self.scanned.end - self.consumed
+        } else {
+            0..0
+        };
+        self.consumed = 0;
+    }
+}
+
+/// The [`Token`]s that were parsed from the input of a [`StreamParser`].
+///
+/// StreamTokens are returned by [`StreamParser::feed`] and [`StreamParser::finish`].
+#[derive(Debug)]
+pub struct StreamTokens<'a> {
+    value: &'a [u8],
+    tokenizer: Tokenizer<'a>,
+    consumed: &'a mut usize,
+    scanned: &'a mut Range<usize>,
+    max_pending_length: usize,
+}
+
+impl<'a> Iterator for StreamTokens<'a> {
+    type Item = Token<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut token = self.tokenizer.next();
+        if token.is_none()
+            && self.tokenizer.partial
+            && self.value.len() - self.tokenizer.position > self.max_pending_length
+        {
+            // too much input is held back, emit the incomplete control function as if the input ended here.
+            self.tokenizer.partial = false;
+            token = self.tokenizer.next();
+            self.tokenizer.partial = true;
+        }
+
+        let token = token.map(|token| match token {
+            RawToken::Text(range) => Token::Bytes(&self.value[range]),
+            RawToken::ControlFunction(control_function) => Token::ControlFunction(control_function),
+            RawToken::ControlString {
+                opener,
+                payload,
+                terminator,
+            } => Token::ByteControlString {
+                opener,
+                payload: &self.value[payload],
+                terminator,
+            },
+            RawToken::Malformed(range, reason) => Token::Malformed {
+                bytes: &self.value[range],
+                reason,
+            },
+        });
+        // remember how much of the input has been handed out, this part can be dropped from the buffer.
+        *self.consumed = self.tokenizer.position;
+        *self.scanned = self.tokenizer.scanned.clone();
+
+        token
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::{
+        c0::{BEL, CR, ESC, LF},
+        c1::{APC, BPH, CSI, DCS, NBH, NEL, OSC, SOS, ST},
+        control_sequences::{
+            Color, ColorEncoding, DeviceAttributes, GraphicRendition, InvalidSequence,
+            PrintQuality, ReversedString, Sequence, TabulationControl, UnderlineStyle, CHA, CHT,
+            CTC, CUP, DA, SGR, SPQR, SR, SRS, SSW, SU, TCC,
+        },
+        control_strings::{
+            device_control_string, operating_system_command,
+            operating_system_command_with_terminator, Terminator,
+        },
+        independent_control_functions::{DMI, EMI, RIS},
+        modes::{Mode, PrivateMode},
+        ControlFunction, Parameter, PrivateParameter,
+    };
+
+    use super::{
+        ByteClass, ByteTokenStream, MalformedReason, OwnedToken, ParseControlFunctionError,
+        StreamParser, Token, TokenStream, BYTE_CLASSES, C0_CODES, C1_CODES, C1_LOWER_BOUND,
+        INDEPENDENT_CODES, INDEPENDENT_LOWER_BOUND,
+    };
+
+    #[test]
+    fn test_simple_ascii_string() {
+        let simple_ascii_input = "Hello World";
+        let mut token_stream = TokenStream::from(simple_ascii_input);
+
+        let first_element = token_stream.next();
+        let second_element = token_stream.next();
+
+        assert!(first_element.is_some_and(|value| value == Token::String(simple_ascii_input)));
+        assert!(second_element.is_none());
+    }
+
+    #[test]
+    fn test_simple_non_ascii_string() {
+        let simple_non_ascii_input = "Löwe 老虎 Léopard";
+        let mut token_stream = TokenStream::from(simple_non_ascii_input);
+
+        let first_element = token_stream.next();
+        let second_element = token_stream.next();
+
+        assert!(first_element.is_some_and(|value| value == Token::String(simple_non_ascii_input)));
+        assert!(second_element.is_none());
+    }
+
+    #[test]
     fn test_simple_ascii_string_with_c0() {
         let simple_ascii_input = "Ring the bell";
         let input = format!("{}{}", simple_ascii_input, BEL);
@@ -708,7 +1982,11 @@ mod tests {
             result,
             vec![
                 Token::ControlFunction(ESC),
-                Token::String(&format!("{}{}", CSI, "ä")),
+                Token::Malformed {
+                    bytes: b"\x1b[",
+                    reason: MalformedReason::NonAsciiByte
+                },
+                Token::String("ä"),
             ]
         )
     }
@@ -722,7 +2000,11 @@ mod tests {
             result,
             vec![
                 Token::ControlFunction(ESC),
-                Token::String(&format!("{}{}{}", CSI, "1;2", "ä")),
+                Token::Malformed {
+                    bytes: b"\x1b[1;2",
+                    reason: MalformedReason::NonAsciiByte
+                },
+                Token::String("ä"),
             ]
         )
     }
@@ -736,7 +2018,10 @@ mod tests {
             result,
             vec![
                 Token::ControlFunction(ESC),
-                Token::String(&format!("{}", CSI)),
+                Token::Malformed {
+                    bytes: b"\x1b[",
+                    reason: MalformedReason::Unterminated
+                },
             ]
         )
     }
@@ -750,7 +2035,11 @@ mod tests {
             result,
             vec![
                 Token::ControlFunction(ESC),
-                Token::String(&format!("{}{}{}", CSI, ascii!(02 / 00), "ä")),
+                Token::Malformed {
+                    bytes: b"\x1b[ ",
+                    reason: MalformedReason::NonAsciiByte
+                },
+                Token::String("ä"),
             ]
         )
     }
@@ -764,7 +2053,11 @@ mod tests {
             result,
             vec![
                 Token::ControlFunction(ESC),
-                Token::String(&format!("{}{}{}{}", CSI, ascii!(02 / 00), "1;2", "ä")),
+                Token::Malformed {
+                    bytes: b"\x1b[ 1;2",
+                    reason: MalformedReason::ParameterAfterIntermediate
+                },
+                Token::String("ä"),
             ]
         )
     }
@@ -778,7 +2071,10 @@ mod tests {
             result,
             vec![
                 Token::ControlFunction(ESC),
-                Token::String(&format!("{}{}", CSI, ascii!(02 / 00))),
+                Token::Malformed {
+                    bytes: b"\x1b[ ",
+                    reason: MalformedReason::Unterminated
+                },
             ]
         )
     }
@@ -792,7 +2088,10 @@ mod tests {
             result,
             vec![
                 Token::ControlFunction(ESC),
-                Token::String(&format!("{}{}", CSI, "1;2")),
+                Token::Malformed {
+                    bytes: b"\x1b[1;2",
+                    reason: MalformedReason::Unterminated
+                },
             ]
         )
     }
@@ -921,7 +2220,7 @@ mod tests {
     #[test]
     fn test_example_a() {
         let example = "\x1b[0u\x1b[62c\x1b[23;6H";
-        let result = TokenStream::from(&example).collect::<Vec<Token>>();
+        let result = TokenStream::from(example).collect::<Vec<Token>>();
 
         assert_eq!(
             result,
@@ -934,4 +2233,1339 @@ mod tests {
             ]
         )
     }
+
+    #[test]
+    fn test_bytes_simple_string() {
+        let input = b"Hello World";
+        let result = ByteTokenStream::from(input).collect::<Vec<Token>>();
+
+        assert_eq!(result, vec![Token::Bytes(input)]);
+    }
+
+    #[test]
+    fn test_bytes_invalid_utf8() {
+        // the first two bytes of a three byte UTF-8 character, followed by a line feed
+        let input = b"L\xC3\xB6we \xE8\x80\n\xE8\x99\x8E";
+        let result = ByteTokenStream::from(input).collect::<Vec<Token>>();
+
+        assert_eq!(
+            result,
+            vec![
+                Token::Bytes(b"L\xC3\xB6we \xE8\x80"),
+                Token::ControlFunction(LF),
+                Token::Bytes(b"\xE8\x99\x8E"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_bytes_control_functions() {
+        let input = format!(
+            "{}Hello{}{}World{}",
+            RIS,
+            BPH,
+            CUP(23.into(), 6.into()),
+            SSW(0)
+        );
+        let result = ByteTokenStream::from(input.as_bytes()).collect::<Vec<Token>>();
+
+        assert_eq!(
+            result,
+            vec![
+                Token::ControlFunction(RIS),
+                Token::Bytes(b"Hello"),
+                Token::ControlFunction(BPH),
+                Token::ControlFunction(CUP(23.into(), 6.into())),
+                Token::Bytes(b"World"),
+                Token::ControlFunction(SSW(0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_bytes_invalid_control_sequence() {
+        let result = ByteTokenStream::from(b"\x1b[1;2\xFF").collect::<Vec<Token>>();
+
+        assert_eq!(
+            result,
+            vec![
+                Token::Malformed {
+                    bytes: b"\x1b[1;2",
+                    reason: MalformedReason::NonAsciiByte
+                },
+                Token::Bytes(b"\xFF")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_stream_sequence_across_chunks() {
+        let mut parser = StreamParser::new();
+
+        let first = parser.feed(b"Line1\x1b").collect::<Vec<Token>>();
+        assert_eq!(first, vec![Token::Bytes(b"Line1")]);
+
+        let second = parser.feed(b"[1").collect::<Vec<Token>>();
+        assert_eq!(second, vec![]);
+
+        let third = parser.feed(b";2HLine2\x1bE").collect::<Vec<Token>>();
+        assert_eq!(
+            third,
+            vec![
+                Token::ControlFunction(CUP(1.into(), 2.into())),
+                Token::Bytes(b"Line2"),
+                Token::ControlFunction(NEL),
+            ]
+        );
+
+        let last = parser.finish().collect::<Vec<Token>>();
+        assert_eq!(last, vec![]);
+    }
+
+    #[test]
+    fn test_stream_finish_with_incomplete_sequence() {
+        let mut parser = StreamParser::new();
+
+        let first = parser.feed(b"Hello\x1b[1;2").collect::<Vec<Token>>();
+        assert_eq!(first, vec![Token::Bytes(b"Hello")]);
+
+        let last = parser.finish().collect::<Vec<Token>>();
+        assert_eq!(
+            last,
+            vec![Token::Malformed {
+                bytes: b"\x1b[1;2",
+                reason: MalformedReason::Unterminated
+            }]
+        );
+    }
+
+    #[test]
+    fn test_stream_finish_with_esc() {
+        let mut parser = StreamParser::new();
+
+        let first = parser.feed(b"\x1b").collect::<Vec<Token>>();
+        assert_eq!(first, vec![]);
+
+        let last = parser.finish().collect::<Vec<Token>>();
+        assert_eq!(last, vec![Token::ControlFunction(ESC)]);
+    }
+
+    #[test]
+    fn test_stream_tokens_not_taken_are_kept() {
+        let mut parser = StreamParser::new();
+
+        // only take the first token, the control function stays in the parser
+        let first = parser.feed(b"Hello\x07").next();
+        assert_eq!(first, Some(Token::Bytes(b"Hello")));
+
+        let second = parser.feed(b"World").collect::<Vec<Token>>();
+        assert_eq!(
+            second,
+            vec![Token::ControlFunction(BEL), Token::Bytes(b"World")]
+        );
+    }
+
+    #[test]
+    fn test_stream_parser_is_reusable_after_finish() {
+        let mut parser = StreamParser::new();
+
+        assert_eq!(parser.feed(b"\x1b[").count(), 0);
+        assert_eq!(parser.finish().count(), 1);
+        assert_eq!(
+            parser.feed(b"\x1bc").collect::<Vec<Token>>(),
+            vec![Token::ControlFunction(RIS)]
+        );
+    }
+
+    #[test]
+    fn test_operating_system_command() {
+        let text = format!(
+            "Title{}Text",
+            operating_system_command("2;Bank Statement 🦀")
+        );
+        let result = TokenStream::from(&text).collect::<Vec<Token>>();
+
+        assert_eq!(
+            result,
+            vec![
+                Token::String("Title"),
+                Token::ControlString {
+                    opener: OSC,
+                    payload: "2;Bank Statement 🦀",
+                    terminator: Terminator::StringTerminator
+                },
+                Token::String("Text")
+            ]
+        )
+    }
+
+    #[test]
+    fn test_device_control_string_with_format_effectors() {
+        let text = device_control_string("line 1\r\nline 2");
+        let result = TokenStream::from(&text).collect::<Vec<Token>>();
+
+        assert_eq!(
+            result,
+            vec![Token::ControlString {
+                opener: DCS,
+                payload: "line 1\r\nline 2",
+                terminator: Terminator::StringTerminator
+            }]
+        )
+    }
+
+    #[test]
+    fn test_empty_control_string() {
+        let text = format!("{}{}", APC, ST);
+        let result = TokenStream::from(&text).collect::<Vec<Token>>();
+
+        assert_eq!(
+            result,
+            vec![Token::ControlString {
+                opener: APC,
+                payload: "",
+                terminator: Terminator::StringTerminator
+            }]
+        )
+    }
+
+    #[test]
+    fn test_character_string_can_contain_control_functions() {
+        let text = format!("{}Bell{}and{}Newline{}", SOS, BEL, NEL, ST);
+        let result = TokenStream::from(&text).collect::<Vec<Token>>();
+
+        assert_eq!(
+            result,
+            vec![Token::ControlString {
+                opener: SOS,
+                payload: "Bell\x07and\x1bENewline",
+                terminator: Terminator::StringTerminator
+            }]
+        )
+    }
+
+    #[test]
+    fn test_character_string_interrupted_by_sos() {
+        let text = format!("{}Start{}Again{}", SOS, SOS, ST);
+        let result = TokenStream::from(&text).collect::<Vec<Token>>();
+
+        assert_eq!(
+            result,
+            vec![
+                Token::ControlFunction(SOS),
+                Token::String("Start"),
+                Token::ControlString {
+                    opener: SOS,
+                    payload: "Again",
+                    terminator: Terminator::StringTerminator
+                }
+            ]
+        )
+    }
+
+    #[test]
+    fn test_command_string_interrupted_by_control_function() {
+        let text = format!("{}0;Title{}Text", OSC, NEL);
+        let result = TokenStream::from(&text).collect::<Vec<Token>>();
+
+        assert_eq!(
+            result,
+            vec![
+                Token::ControlFunction(OSC),
+                Token::String("0;Title"),
+                Token::ControlFunction(NEL),
+                Token::String("Text")
+            ]
+        )
+    }
+
+    #[test]
+    fn test_operating_system_command_terminated_by_bell() {
+        let text = operating_system_command_with_terminator("0;Title", Terminator::Bell);
+        let result = TokenStream::from(&text).collect::<Vec<Token>>();
+
+        assert_eq!(
+            result,
+            vec![Token::ControlString {
+                opener: OSC,
+                payload: "0;Title",
+                terminator: Terminator::Bell
+            }]
+        )
+    }
+
+    #[test]
+    fn test_operating_system_command_round_trip() {
+        for terminator in [Terminator::StringTerminator, Terminator::Bell] {
+            let text =
+                operating_system_command_with_terminator("8;;https://example.com", terminator);
+
+            let Some(Token::ControlString {
+                payload,
+                terminator,
+                ..
+            }) = TokenStream::from(&text).next()
+            else {
+                panic!("Expected a control string");
+            };
+
+            assert_eq!(
+                operating_system_command_with_terminator(payload, terminator),
+                text
+            );
+        }
+    }
+
+    #[test]
+    fn test_operating_system_command_with_bell_terminator_disabled() {
+        let text = format!("{}0;Title{}", OSC, BEL);
+        let result = TokenStream::from(&text)
+            .accept_bell_terminator(false)
+            .collect::<Vec<Token>>();
+
+        assert_eq!(
+            result,
+            vec![
+                Token::ControlFunction(OSC),
+                Token::String("0;Title"),
+                Token::ControlFunction(BEL)
+            ]
+        )
+    }
+
+    #[test]
+    fn test_bell_does_not_terminate_other_control_strings() {
+        let text = format!("{}1$r{}", DCS, BEL);
+        let result = TokenStream::from(&text).collect::<Vec<Token>>();
+
+        assert_eq!(
+            result,
+            vec![
+                Token::ControlFunction(DCS),
+                Token::String("1$r"),
+                Token::ControlFunction(BEL)
+            ]
+        )
+    }
+
+    #[test]
+    fn test_unterminated_control_string() {
+        let text = format!("{}0;Title", OSC);
+        let result = TokenStream::from(&text).collect::<Vec<Token>>();
+
+        assert_eq!(
+            result,
+            vec![Token::ControlFunction(OSC), Token::String("0;Title")]
+        )
+    }
+
+    #[test]
+    fn test_bytes_control_string() {
+        let result = ByteTokenStream::from(b"\x1bP1$r\x1b\\\xff").collect::<Vec<Token>>();
+
+        assert_eq!(
+            result,
+            vec![
+                Token::ByteControlString {
+                    opener: DCS,
+                    payload: b"1$r",
+                    terminator: Terminator::StringTerminator
+                },
+                Token::Bytes(b"\xff")
+            ]
+        )
+    }
+
+    #[test]
+    fn test_bytes_control_string_with_invalid_utf8() {
+        let result = ByteTokenStream::from(b"\x1b]0;\xff\x1b\\").collect::<Vec<Token>>();
+
+        assert_eq!(
+            result,
+            vec![Token::ByteControlString {
+                opener: OSC,
+                payload: b"0;\xff",
+                terminator: Terminator::StringTerminator
+            }]
+        );
+
+        let result = ByteTokenStream::from(b"\x1b]0;\xff\xfe\x07").collect::<Vec<Token>>();
+
+        assert_eq!(
+            result,
+            vec![Token::ByteControlString {
+                opener: OSC,
+                payload: b"0;\xff\xfe",
+                terminator: Terminator::Bell
+            }]
+        )
+    }
+
+    #[test]
+    fn test_stream_control_string_across_chunks() {
+        let mut parser = StreamParser::new();
+
+        assert_eq!(
+            parser.feed(b"Text\x1b]0;Ti").collect::<Vec<Token>>(),
+            vec![Token::Bytes(b"Text")]
+        );
+        assert_eq!(parser.feed(b"tle\x1b").count(), 0);
+        assert_eq!(
+            parser.feed(b"\\").collect::<Vec<Token>>(),
+            vec![Token::ByteControlString {
+                opener: OSC,
+                payload: b"0;Title",
+                terminator: Terminator::StringTerminator
+            }]
+        );
+    }
+
+    #[test]
+    fn test_stream_finish_with_incomplete_control_string() {
+        let mut parser = StreamParser::new();
+
+        assert_eq!(parser.feed(b"\x1b]0;Title").count(), 0);
+        assert_eq!(
+            parser.finish().collect::<Vec<Token>>(),
+            vec![Token::ControlFunction(OSC), Token::Bytes(b"0;Title")]
+        );
+    }
+
+    #[test]
+    fn test_stream_control_string_in_many_chunks() {
+        let mut parser = StreamParser::new().accept_unicode_c1(true);
+
+        assert_eq!(parser.feed(b"\x1bP").count(), 0);
+        for _ in 0..1000 {
+            assert_eq!(parser.feed(b"data;").count(), 0);
+        }
+        // the terminator is split across chunks
+        assert_eq!(parser.feed(b"end\xc2").count(), 0);
+        let result = parser.feed(b"\x9cText").collect::<Vec<Token>>();
+        let payload = format!("{}end", "data;".repeat(1000));
+        assert_eq!(
+            result,
+            vec![
+                Token::ByteControlString {
+                    opener: DCS,
+                    payload: payload.as_bytes(),
+                    terminator: Terminator::StringTerminator
+                },
+                Token::Bytes(b"Text")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_stream_character_string_resumes_after_invalid_scan() {
+        let mut parser = StreamParser::new();
+
+        // the character string contains an operating system command, which is held back until the character string is
+        // known to be invalid.
+        assert_eq!(
+            parser
+                .feed(b"Text\x1bX\x1b]0;Title")
+                .collect::<Vec<Token>>(),
+            vec![Token::Bytes(b"Text")]
+        );
+        assert_eq!(
+            parser.feed(b"\x07\x1bX").collect::<Vec<Token>>(),
+            vec![
+                Token::ControlFunction(SOS),
+                Token::ByteControlString {
+                    opener: OSC,
+                    payload: b"0;Title",
+                    terminator: Terminator::Bell
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn test_stream_max_pending_length_control_string() {
+        let mut parser = StreamParser::new().max_pending_length(8);
+
+        assert_eq!(parser.feed(b"\x1b]0;Ti").count(), 0);
+        assert_eq!(
+            parser.feed(b"tle\x1b[1").collect::<Vec<Token>>(),
+            vec![Token::ControlFunction(OSC), Token::Bytes(b"0;Title")]
+        );
+        assert_eq!(
+            parser.feed(b"m").collect::<Vec<Token>>(),
+            vec![Token::ControlFunction(SGR(Some(vec![
+                GraphicRendition::HighIntensity
+            ])))]
+        );
+    }
+
+    #[test]
+    fn test_stream_max_pending_length_control_sequence() {
+        let mut parser = StreamParser::new().max_pending_length(4);
+
+        assert_eq!(parser.feed(b"\x1b[1;").count(), 0);
+        assert_eq!(
+            parser.feed(b"2;3").collect::<Vec<Token>>(),
+            vec![Token::Malformed {
+                bytes: b"\x1b[1;2;3",
+                reason: MalformedReason::Unterminated
+            }]
+        );
+        assert_eq!(
+            parser.feed(b"H").collect::<Vec<Token>>(),
+            vec![Token::Bytes(b"H")]
+        );
+    }
+
+    #[test]
+    fn test_stream_bell_terminator_disabled() {
+        let mut parser = StreamParser::new().accept_bell_terminator(false);
+
+        assert_eq!(parser.feed(b"\x1b]0;Title").count(), 0);
+        assert_eq!(
+            parser.feed(b"\x07").collect::<Vec<Token>>(),
+            vec![
+                Token::ControlFunction(OSC),
+                Token::Bytes(b"0;Title"),
+                Token::ControlFunction(BEL)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_bytes_8bit_c1_disabled_by_default() {
+        let result = ByteTokenStream::from(b"\x9b1;2H\x85").collect::<Vec<Token>>();
+
+        assert_eq!(result, vec![Token::Bytes(b"\x9b1;2H\x85")])
+    }
+
+    #[test]
+    fn test_bytes_8bit_c1() {
+        let result = ByteTokenStream::from(b"Line 1\x85Line 2\x9b1;2H\x80")
+            .accept_8bit_c1(true)
+            .collect::<Vec<Token>>();
+
+        assert_eq!(
+            result,
+            vec![
+                Token::Bytes(b"Line 1"),
+                Token::ControlFunction(NEL),
+                Token::Bytes(b"Line 2"),
+                Token::ControlFunction(CUP(1.into(), 2.into())),
+                Token::Bytes(b"\x80")
+            ]
+        )
+    }
+
+    #[test]
+    fn test_bytes_8bit_invalid_control_sequence() {
+        let result = ByteTokenStream::from(b"\x9b1;\x85")
+            .accept_8bit_c1(true)
+            .collect::<Vec<Token>>();
+
+        assert_eq!(
+            result,
+            vec![
+                Token::Malformed {
+                    bytes: b"\x9b1;",
+                    reason: MalformedReason::NonAsciiByte
+                },
+                Token::ControlFunction(NEL)
+            ]
+        )
+    }
+
+    #[test]
+    fn test_bytes_8bit_control_string() {
+        let result = ByteTokenStream::from(b"\x9d0;Title\x9c\x901$r\x1b\\")
+            .accept_8bit_c1(true)
+            .collect::<Vec<Token>>();
+
+        assert_eq!(
+            result,
+            vec![
+                Token::ByteControlString {
+                    opener: OSC,
+                    payload: b"0;Title",
+                    terminator: Terminator::StringTerminator
+                },
+                Token::ByteControlString {
+                    opener: DCS,
+                    payload: b"1$r",
+                    terminator: Terminator::StringTerminator
+                }
+            ]
+        )
+    }
+
+    #[test]
+    fn test_bytes_8bit_round_trip() {
+        let private_sequence = SU(Some(2)).with_private_parameter(PrivateParameter::GreaterThan);
+
+        let mut bytes = Vec::new();
+        CUP(3.into(), 4.into()).write_8bit_to(&mut bytes).unwrap();
+        bytes.extend_from_slice(b"Text");
+        NEL.write_8bit_to(&mut bytes).unwrap();
+        private_sequence.write_8bit_to(&mut bytes).unwrap();
+        let result = ByteTokenStream::from(&bytes)
+            .accept_8bit_c1(true)
+            .collect::<Vec<Token>>();
+
+        assert_eq!(
+            result,
+            vec![
+                Token::ControlFunction(CUP(3.into(), 4.into())),
+                Token::Bytes(b"Text"),
+                Token::ControlFunction(NEL),
+                Token::ControlFunction(private_sequence)
+            ]
+        )
+    }
+
+    #[test]
+    fn test_stream_8bit_sequence_across_chunks() {
+        let mut parser = StreamParser::new().accept_8bit_c1(true);
+
+        assert_eq!(
+            parser.feed(b"Text\x9b1;").collect::<Vec<Token>>(),
+            vec![Token::Bytes(b"Text")]
+        );
+        assert_eq!(
+            parser.feed(b"2H").collect::<Vec<Token>>(),
+            vec![Token::ControlFunction(CUP(1.into(), 2.into()))]
+        );
+    }
+
+    #[test]
+    fn test_unicode_c1_disabled_by_default() {
+        let text = "\u{009B}1;2H\u{0085}";
+        let result = TokenStream::from(text).collect::<Vec<Token>>();
+
+        assert_eq!(result, vec![Token::String(text)])
+    }
+
+    #[test]
+    fn test_unicode_c1() {
+        let text = "老\u{0085}虎\u{009B}1;2H\u{0080}";
+        let result = TokenStream::from(text)
+            .accept_unicode_c1(true)
+            .collect::<Vec<Token>>();
+
+        assert_eq!(
+            result,
+            vec![
+                Token::String("老"),
+                Token::ControlFunction(NEL),
+                Token::String("虎"),
+                Token::ControlFunction(CUP(1.into(), 2.into())),
+                Token::String("\u{0080}")
+            ]
+        )
+    }
+
+    #[test]
+    fn test_unicode_c1_invalid_control_sequence() {
+        let text = "\u{009B}1;老";
+        let result = TokenStream::from(text)
+            .accept_unicode_c1(true)
+            .collect::<Vec<Token>>();
+
+        assert_eq!(
+            result,
+            vec![
+                Token::Malformed {
+                    bytes: "\u{009B}1;".as_bytes(),
+                    reason: MalformedReason::NonAsciiByte
+                },
+                Token::String("老")
+            ]
+        )
+    }
+
+    #[test]
+    fn test_unicode_c1_control_string() {
+        let text = "\u{009D}0;Title\u{009C}\u{0098}老\u{0085}虎\u{009C}";
+        let result = TokenStream::from(text)
+            .accept_unicode_c1(true)
+            .collect::<Vec<Token>>();
+
+        assert_eq!(
+            result,
+            vec![
+                Token::ControlString {
+                    opener: OSC,
+                    payload: "0;Title",
+                    terminator: Terminator::StringTerminator
+                },
+                Token::ControlString {
+                    opener: SOS,
+                    payload: "老\u{0085}虎",
+                    terminator: Terminator::StringTerminator
+                }
+            ]
+        )
+    }
+
+    #[test]
+    fn test_unicode_c1_round_trip() {
+        // the unicode code points U+0080 to U+009F correspond to the bytes of the 8-bit representation.
+        let text = [
+            CUP(3.into(), 4.into()).to_8bit_bytes(),
+            b"Text".to_vec(),
+            NEL.to_8bit_bytes(),
+        ]
+        .concat()
+        .into_iter()
+        .map(char::from)
+        .collect::<String>();
+        let result = TokenStream::from(&text)
+            .accept_unicode_c1(true)
+            .collect::<Vec<Token>>();
+
+        assert_eq!(
+            result,
+            vec![
+                Token::ControlFunction(CUP(3.into(), 4.into())),
+                Token::String("Text"),
+                Token::ControlFunction(NEL)
+            ]
+        )
+    }
+
+    #[test]
+    fn test_stream_unicode_c1_across_chunks() {
+        let mut parser = StreamParser::new().accept_unicode_c1(true);
+
+        assert_eq!(
+            parser.feed(b"Text\xc2").collect::<Vec<Token>>(),
+            vec![Token::Bytes(b"Text")]
+        );
+        assert_eq!(
+            parser.feed(b"\x85").collect::<Vec<Token>>(),
+            vec![Token::ControlFunction(NEL)]
+        );
+    }
+
+    #[test]
+    fn test_control_sequence_with_intermediates() {
+        let text = "\x1b[1!p\x1b[2 q\x1b[?$r";
+        let result = TokenStream::from(text).collect::<Vec<Token>>();
+
+        assert_eq!(
+            result,
+            vec![
+                Token::ControlFunction(
+                    ControlFunction::private_use("!p", vec![String::from("1")]).unwrap()
+                ),
+                Token::ControlFunction(
+                    ControlFunction::private_use(" q", vec![String::from("2")]).unwrap()
+                ),
+                Token::ControlFunction(
+                    ControlFunction::private_use("$r", vec![])
+                        .unwrap()
+                        .with_private_parameter(PrivateParameter::QuestionMark)
+                ),
+            ]
+        )
+    }
+
+    #[test]
+    fn test_control_sequence_with_multiple_intermediates() {
+        let text = "\x1b[1 !\"pText";
+        let result = TokenStream::from(text).collect::<Vec<Token>>();
+
+        assert_eq!(
+            result,
+            vec![
+                Token::ControlFunction(
+                    ControlFunction::private_use(" !\"p", vec![String::from("1")]).unwrap()
+                ),
+                Token::String("Text")
+            ]
+        )
+    }
+
+    #[test]
+    fn test_malformed_control_sequence_with_parameter_after_intermediate() {
+        let text = "\x1b[ 1;2HText";
+        let result = TokenStream::from(text).collect::<Vec<Token>>();
+
+        assert_eq!(
+            result,
+            vec![
+                Token::Malformed {
+                    bytes: b"\x1b[ 1;2H",
+                    reason: MalformedReason::ParameterAfterIntermediate
+                },
+                Token::String("Text")
+            ]
+        )
+    }
+
+    #[test]
+    fn test_malformed_control_sequence_interrupted_by_control_function() {
+        let text = format!("{}1;2{}Text", CSI, CUP(None, None));
+        let result = TokenStream::from(&text).collect::<Vec<Token>>();
+
+        assert_eq!(
+            result,
+            vec![
+                Token::Malformed {
+                    bytes: b"\x1b[1;2",
+                    reason: MalformedReason::Unterminated
+                },
+                Token::ControlFunction(CUP(None, None)),
+                Token::String("Text")
+            ]
+        )
+    }
+
+    #[test]
+    fn test_malformed_control_sequence_keeps_first_reason() {
+        let text = "\x1b[!1ä";
+        let result = TokenStream::from(text).collect::<Vec<Token>>();
+
+        assert_eq!(
+            result,
+            vec![
+                Token::Malformed {
+                    bytes: b"\x1b[!1",
+                    reason: MalformedReason::ParameterAfterIntermediate
+                },
+                Token::String("ä")
+            ]
+        )
+    }
+
+    #[test]
+    fn test_control_sequence_with_private_parameter() {
+        let text = "\x1b[?1049h\x1b[>0c\x1b[<0;10;20M\x1b[=1;2c";
+        let result = TokenStream::from(text).collect::<Vec<Token>>();
+
+        let expected = [
+            (PrivateParameter::QuestionMark, "\x1b[?1049h"),
+            (PrivateParameter::GreaterThan, "\x1b[>0c"),
+            (PrivateParameter::LessThan, "\x1b[<0;10;20M"),
+            (PrivateParameter::Equals, "\x1b[=1;2c"),
+        ];
+        assert_eq!(result.len(), expected.len());
+        for (token, (private_parameter, sequence)) in result.iter().zip(expected) {
+            let Token::ControlFunction(control_function) = token else {
+                panic!("Expected a control function, got {:?}", token);
+            };
+            assert_eq!(
+                control_function.private_parameter(),
+                Some(private_parameter)
+            );
+            assert_eq!(control_function.to_string(), sequence);
+        }
+
+        assert_eq!(
+            result[1],
+            Token::ControlFunction(DA(None).with_private_parameter(PrivateParameter::GreaterThan))
+        );
+    }
+
+    #[test]
+    fn test_private_parameter_only_at_start() {
+        let text = "\x1b[1;?2H";
+        let result = TokenStream::from(text).collect::<Vec<Token>>();
+
+        assert_eq!(
+            result,
+            vec![Token::Malformed {
+                bytes: text.as_bytes(),
+                reason: MalformedReason::InvalidParameter
+            }]
+        )
+    }
+
+    #[test]
+    fn test_control_sequence_with_sub_parameters() {
+        let text = "\x1b[4:3;38:2::255:128:0m";
+        let result = TokenStream::from(text).collect::<Vec<Token>>();
+
+        let Token::ControlFunction(control_function) = &result[0] else {
+            panic!("Expected a control function, got {:?}", result[0]);
+        };
+        assert_eq!(
+            control_function.parameters(),
+            vec![
+                Parameter::new(vec![Some(4), Some(3)]),
+                Parameter::new(vec![Some(38), Some(2), None, Some(255), Some(128), Some(0)])
+            ]
+        );
+        assert_eq!(control_function.to_string(), text);
+    }
+
+    #[test]
+    fn test_control_sequence_without_parameters() {
+        let text = "\x1b[m\x1b[;H";
+        let result = TokenStream::from(text).collect::<Vec<Token>>();
+
+        let parameters = result
+            .iter()
+            .map(|token| match token {
+                Token::ControlFunction(control_function) => control_function.parameters().to_vec(),
+                _ => panic!("Expected a control function, got {:?}", token),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            parameters,
+            vec![
+                vec![],
+                vec![Parameter::new(vec![None]), Parameter::new(vec![None])]
+            ]
+        );
+    }
+
+    #[test]
+    fn test_control_sequence_with_too_large_parameter() {
+        let text = "\x1b[4294967296H";
+        let result = TokenStream::from(text).collect::<Vec<Token>>();
+
+        assert_eq!(
+            result,
+            vec![Token::Malformed {
+                bytes: text.as_bytes(),
+                reason: MalformedReason::InvalidParameter
+            }]
+        )
+    }
+
+    /// Decodes the control sequence of a single control function token.
+    fn decode(text: &str) -> Result<Sequence, InvalidSequence> {
+        match TokenStream::from(text).next() {
+            Some(Token::ControlFunction(control_function)) => Sequence::try_from(&control_function),
+            token => panic!("Expected a control function, got {:?}", token),
+        }
+    }
+
+    #[test]
+    fn test_decode_control_sequences() {
+        assert_eq!(
+            decode("\x1b[H"),
+            Ok(Sequence::CursorPosition { line: 1, column: 1 })
+        );
+        assert_eq!(
+            decode("\x1b[;7H"),
+            Ok(Sequence::CursorPosition { line: 1, column: 7 })
+        );
+        assert_eq!(decode("\x1b[3A"), Ok(Sequence::CursorUp(3)));
+        assert_eq!(
+            decode("\x1b[m"),
+            Ok(Sequence::SelectGraphicRendition(vec![
+                GraphicRendition::Default
+            ]))
+        );
+        assert_eq!(
+            decode("\x1b[1;;31m"),
+            Ok(Sequence::SelectGraphicRendition(vec![
+                GraphicRendition::HighIntensity,
+                GraphicRendition::Default,
+                GraphicRendition::RedForeground
+            ]))
+        );
+        assert_eq!(
+            decode("\x1b[4;12h"),
+            Ok(Sequence::SetMode(vec![
+                Mode::InsertionReplacementMode,
+                Mode::SendReceiveMode
+            ]))
+        );
+        assert_eq!(decode("\x1b[1 h"), Ok(Sequence::SetLineSpacing(1)));
+        assert_eq!(
+            decode("\x1b[5 c"),
+            Ok(Sequence::TabulationCentredOnCharacter {
+                position: 5,
+                character: 32
+            })
+        );
+    }
+
+    #[test]
+    fn test_decode_generated_control_sequences() {
+        assert_eq!(
+            Sequence::try_from(&DA(Some(DeviceAttributes::Identify(4)))),
+            Ok(Sequence::DeviceAttributes(DeviceAttributes::Identify(4)))
+        );
+        assert_eq!(
+            Sequence::try_from(&CTC(Some(TabulationControl::ClearLineTabulationStop))),
+            Ok(Sequence::CursorTabulationControl(
+                TabulationControl::ClearLineTabulationStop
+            ))
+        );
+        assert_eq!(
+            Sequence::try_from(&SPQR(Some(PrintQuality::HighQualityLowSpeed))),
+            Ok(Sequence::SelectPrintQualityAndRapidity(
+                PrintQuality::HighQualityLowSpeed
+            ))
+        );
+        assert_eq!(
+            Sequence::try_from(&SR(Some(4))),
+            Ok(Sequence::ScrollRight(4))
+        );
+    }
+
+    #[test]
+    fn test_decode_invalid_control_sequences() {
+        assert_eq!(
+            decode("\x1b[3J"),
+            Err(InvalidSequence::InvalidSelection { index: 0, value: 3 })
+        );
+        assert_eq!(
+            decode("\x1b[1;26m"),
+            Err(InvalidSequence::InvalidSelection {
+                index: 1,
+                value: 26
+            })
+        );
+        assert_eq!(decode("\x1b[ W"), Err(InvalidSequence::MissingParameter(0)));
+        assert_eq!(
+            decode("\x1b[4;h"),
+            Err(InvalidSequence::MissingParameter(1))
+        );
+        assert_eq!(decode("\x1b[>4;1m"), Err(InvalidSequence::NotStandardized));
+        assert_eq!(decode("\x1b[2 q"), Err(InvalidSequence::NotStandardized));
+        assert_eq!(decode("\x1bE"), Err(InvalidSequence::NotStandardized));
+    }
+
+    #[test]
+    fn test_decode_extended_colors() {
+        assert_eq!(
+            decode("\x1b[1;38;5;208;48;2;10;20;30;4m"),
+            Ok(Sequence::SelectGraphicRendition(vec![
+                GraphicRendition::HighIntensity,
+                GraphicRendition::Foreground(Color::Indexed(208)),
+                GraphicRendition::Background(Color::Rgb(10, 20, 30)),
+                GraphicRendition::Underlined
+            ]))
+        );
+        assert_eq!(
+            decode("\x1b[38:5:208;48:2::10:20:30;58:2:10:20:30m"),
+            Ok(Sequence::SelectGraphicRendition(vec![
+                GraphicRendition::Foreground(Color::Indexed(208)),
+                GraphicRendition::Background(Color::Rgb(10, 20, 30)),
+                GraphicRendition::UnderlineColor(Color::Rgb(10, 20, 30))
+            ]))
+        );
+
+        let graphic_renditions = vec![
+            GraphicRendition::UnderlineColor(Color::Indexed(1)),
+            GraphicRendition::Foreground(Color::Rgb(255, 128, 0)),
+            GraphicRendition::DefaultForeground,
+        ];
+        assert_eq!(
+            Sequence::try_from(&SGR(Some(graphic_renditions.clone()))),
+            Ok(Sequence::SelectGraphicRendition(graphic_renditions.clone()))
+        );
+
+        let sub_parameters = ColorEncoding::SubParameters.sgr(Some(graphic_renditions.clone()));
+        assert_eq!(sub_parameters, "\x1b[58:5:1;38:2::255:128:0;39m");
+        assert_eq!(
+            decode(&sub_parameters.to_string()),
+            Ok(Sequence::SelectGraphicRendition(graphic_renditions))
+        );
+
+        assert_eq!(
+            decode("\x1b[38;5m"),
+            Err(InvalidSequence::MissingParameter(2))
+        );
+        assert_eq!(
+            decode("\x1b[1;48;2;1;2m"),
+            Err(InvalidSequence::MissingParameter(5))
+        );
+        assert_eq!(
+            decode("\x1b[38;5;300m"),
+            Err(InvalidSequence::InvalidSelection {
+                index: 2,
+                value: 300
+            })
+        );
+        assert_eq!(
+            decode("\x1b[1;38;3m"),
+            Err(InvalidSequence::InvalidSelection { index: 2, value: 3 })
+        );
+        assert_eq!(
+            decode("\x1b[38:3:1:2:3m"),
+            Err(InvalidSequence::InvalidSelection { index: 0, value: 3 })
+        );
+        assert_eq!(
+            decode("\x1b[38:5:300m"),
+            Err(InvalidSequence::InvalidSelection {
+                index: 0,
+                value: 300
+            })
+        );
+        assert_eq!(
+            decode("\x1b[1:2m"),
+            Err(InvalidSequence::UnexpectedSubParameter(0))
+        );
+    }
+
+    #[test]
+    fn test_decode_private_modes() {
+        assert_eq!(
+            decode("\x1b[?1049;25h"),
+            Ok(Sequence::SetPrivateMode(vec![
+                PrivateMode::AlternateScreenBuffer,
+                PrivateMode::TextCursorEnableMode
+            ]))
+        );
+        assert_eq!(
+            decode("\x1b[?2004l"),
+            Ok(Sequence::ResetPrivateMode(vec![
+                PrivateMode::BracketedPasteMode
+            ]))
+        );
+        assert_eq!(
+            Sequence::try_from(&PrivateMode::SynchronizedOutput.set()),
+            Ok(Sequence::SetPrivateMode(vec![
+                PrivateMode::SynchronizedOutput
+            ]))
+        );
+
+        assert_eq!(
+            decode("\x1b[?1000;1047h"),
+            Err(InvalidSequence::InvalidSelection {
+                index: 1,
+                value: 1047
+            })
+        );
+        assert_eq!(decode("\x1b[?1049m"), Err(InvalidSequence::NotStandardized));
+        assert_eq!(decode("\x1b[>1049h"), Err(InvalidSequence::NotStandardized));
+    }
+
+    #[test]
+    fn test_decode_underline_styles() {
+        assert_eq!(
+            decode("\x1b[4;4:3;4:;58:5:1;59;24m"),
+            Ok(Sequence::SelectGraphicRendition(vec![
+                GraphicRendition::Underlined,
+                GraphicRendition::UnderlineStyle(UnderlineStyle::Curly),
+                GraphicRendition::UnderlineStyle(UnderlineStyle::None),
+                GraphicRendition::UnderlineColor(Color::Indexed(1)),
+                GraphicRendition::DefaultUnderlineColor,
+                GraphicRendition::NotUnderlined
+            ]))
+        );
+
+        let graphic_renditions = vec![
+            GraphicRendition::UnderlineStyle(UnderlineStyle::Dotted),
+            GraphicRendition::UnderlineColor(Color::Rgb(255, 0, 0)),
+            GraphicRendition::UnderlineStyle(UnderlineStyle::Dashed),
+        ];
+        assert_eq!(
+            Sequence::try_from(&SGR(Some(graphic_renditions.clone()))),
+            Ok(Sequence::SelectGraphicRendition(graphic_renditions))
+        );
+
+        assert_eq!(
+            decode("\x1b[4:6m"),
+            Err(InvalidSequence::InvalidSelection { index: 0, value: 6 })
+        );
+    }
+
+    #[test]
+    fn test_owned_tokens() {
+        let input = b"\x1b[?25u\x1b]0;title\x07text\xFF\x1b[1;\xFFm".to_vec();
+        let tokens = ByteTokenStream::from(&input[..])
+            .map(Token::into_owned)
+            .collect::<Vec<OwnedToken>>();
+
+        let handle = std::thread::spawn(move || tokens);
+        let tokens = handle.join().unwrap();
+
+        assert_eq!(
+            tokens,
+            ByteTokenStream::from(&input[..]).collect::<Vec<Token>>()
+        );
+        assert_eq!(
+            tokens,
+            vec![
+                OwnedToken::ControlFunction(
+                    ControlFunction::private_use("u", vec![String::from("25")])
+                        .unwrap()
+                        .with_private_parameter(PrivateParameter::QuestionMark)
+                        .into_owned()
+                ),
+                OwnedToken::ByteControlString {
+                    opener: OSC,
+                    payload: b"0;title".to_vec(),
+                    terminator: Terminator::Bell
+                },
+                OwnedToken::Bytes(b"text\xFF".to_vec()),
+                OwnedToken::Malformed {
+                    bytes: b"\x1b[1;".to_vec(),
+                    reason: MalformedReason::NonAsciiByte
+                },
+                OwnedToken::Bytes(b"\xFFm".to_vec()),
+            ]
+        );
+        assert_ne!(tokens[2], Token::String("text"));
+    }
+
+    /// Test that the lookup tables of the tokenizer are indexed by the bytes identifying the control functions.
+    #[test]
+    fn test_lookup_tables() {
+        for (byte, c0_code) in C0_CODES.iter().enumerate() {
+            assert_eq!(c0_code.value.as_bytes(), [byte as u8]);
+        }
+
+        for (index, c1_code) in C1_CODES.iter().enumerate() {
+            if let Some(c1_code) = c1_code {
+                assert_eq!(c1_code.value.as_bytes(), [C1_LOWER_BOUND + index as u8]);
+            }
+        }
+        assert_eq!(C1_CODES.iter().flatten().count(), 27);
+
+        for (index, independent_code) in INDEPENDENT_CODES.iter().enumerate() {
+            if let Some(independent_code) = independent_code {
+                assert_eq!(
+                    independent_code.value.as_bytes(),
+                    [INDEPENDENT_LOWER_BOUND + index as u8]
+                );
+            }
+        }
+        assert_eq!(INDEPENDENT_CODES.iter().flatten().count(), 10);
+
+        assert_eq!(BYTE_CLASSES[0x00], ByteClass::C0);
+        assert_eq!(BYTE_CLASSES[0x1B], ByteClass::Escape);
+        assert_eq!(BYTE_CLASSES[0x1F], ByteClass::C0);
+        assert_eq!(BYTE_CLASSES[0x20], ByteClass::Text);
+        assert_eq!(BYTE_CLASSES[0x7F], ByteClass::Text);
+        assert_eq!(BYTE_CLASSES[0x80], ByteClass::NonAscii);
+        assert_eq!(BYTE_CLASSES[0xFF], ByteClass::NonAscii);
+    }
+
+    /// Test the byte ranges of spanned tokens.
+    #[test]
+    fn test_spanned() {
+        let input = "\x1b[1mHällo\x1b]0;title\x07\x1b[1;\x1b[0mWörld\x07";
+        let spans = TokenStream::from(input).spanned().collect::<Vec<_>>();
+        assert_eq!(
+            spans,
+            vec![
+                (
+                    0..4,
+                    Token::ControlFunction(SGR(Some(vec![GraphicRendition::HighIntensity])))
+                ),
+                (4..10, Token::String("Hällo")),
+                (
+                    10..20,
+                    Token::ControlString {
+                        opener: OSC,
+                        payload: "0;title",
+                        terminator: Terminator::Bell
+                    }
+                ),
+                (
+                    20..24,
+                    Token::Malformed {
+                        bytes: b"\x1b[1;",
+                        reason: MalformedReason::Unterminated
+                    }
+                ),
+                (24..28, Token::ControlFunction(SGR(None))),
+                (28..34, Token::String("Wörld")),
+                (34..35, Token::ControlFunction(BEL)),
+            ]
+        );
+        for (range, token) in spans {
+            assert_eq!(
+                TokenStream::from(&input[range]).collect::<Vec<_>>(),
+                vec![token]
+            );
+        }
+
+        let input = b"\xFF\x1b[1;1H";
+        assert_eq!(
+            ByteTokenStream::from(input).spanned().collect::<Vec<_>>(),
+            vec![
+                (0..1, Token::Bytes(b"\xFF")),
+                (1..7, Token::ControlFunction(CUP(None, None)))
+            ]
+        );
+    }
+
+    /// Test parsing strings that contain exactly one control function.
+    #[test]
+    fn test_parse_one() {
+        assert_eq!(ControlFunction::parse_one("\x07"), Ok(BEL));
+        assert_eq!(ControlFunction::parse_one("\x1bE"), Ok(NEL));
+        assert_eq!(ControlFunction::parse_one("\x1bc"), Ok(RIS));
+        assert_eq!(
+            ControlFunction::parse_one("\x1b[?25u"),
+            Ok(ControlFunction::private_use("u", vec![String::from("25")])
+                .unwrap()
+                .with_private_parameter(PrivateParameter::QuestionMark))
+        );
+        assert_eq!(
+            "\x1b[1;31m".parse::<ControlFunction>(),
+            Ok(SGR(Some(vec![
+                GraphicRendition::HighIntensity,
+                GraphicRendition::RedForeground
+            ])))
+        );
+
+        assert_eq!(
+            ControlFunction::parse_one(""),
+            Err(ParseControlFunctionError::Empty)
+        );
+        assert_eq!(
+            ControlFunction::parse_one("text\x07"),
+            Err(ParseControlFunctionError::NotAControlFunction)
+        );
+        assert_eq!(
+            ControlFunction::parse_one("\x1b]0;title\x07"),
+            Err(ParseControlFunctionError::ControlString)
+        );
+        assert_eq!(
+            ControlFunction::parse_one("\x1b[1;2"),
+            Err(ParseControlFunctionError::Malformed(
+                MalformedReason::Unterminated
+            ))
+        );
+        assert_eq!(
+            ControlFunction::parse_one("\x07text"),
+            Err(ParseControlFunctionError::TrailingInput(1))
+        );
+        assert_eq!(
+            ControlFunction::parse_one("\x1b[H\x1b[J"),
+            Err(ParseControlFunctionError::TrailingInput(3))
+        );
+        assert_eq!(
+            ParseControlFunctionError::TrailingInput(3).to_string(),
+            "Expected a single control function, found more input at byte 3"
+        );
+    }
+
+    /// Test that tokens can be cloned, counted in a hash map and sorted.
+    #[test]
+    fn test_token_frequencies() {
+        let input = "a\x1b[1mb\x1b[0mc\x1b[1m\x07";
+        let mut frequencies = std::collections::HashMap::new();
+        for token in TokenStream::from(input) {
+            *frequencies.entry(token.clone()).or_insert(0) += 1;
+        }
+
+        assert_eq!(frequencies.len(), 6);
+        assert_eq!(
+            frequencies[&Token::ControlFunction(SGR(Some(vec![GraphicRendition::HighIntensity])))],
+            2
+        );
+
+        let mut tokens = frequencies.into_keys().collect::<Vec<Token>>();
+        tokens.sort();
+        assert_eq!(
+            tokens,
+            vec![
+                Token::String("a"),
+                Token::String("b"),
+                Token::String("c"),
+                Token::ControlFunction(BEL),
+                Token::ControlFunction(SGR(None)),
+                Token::ControlFunction(SGR(Some(vec![GraphicRendition::HighIntensity]))),
+            ]
+        );
+    }
 }
@@ -0,0 +1,411 @@
+//! Text Styles.
+//!
+//! The graphic rendition of text is established by SELECT GRAPHIC RENDITION ([`SGR`]). Each occurrence of [`SGR`]
+//! changes some aspects of the graphic rendition, and leaves the other aspects untouched. A [`TextStyle`] keeps track
+//! of all aspects that are in effect.
+//!
+//! This module contains higher level functions to follow the graphic rendition of a data stream, and to change from
+//! one graphic rendition to another with as few parameters as possible.
+//!
+//! ## Usage
+//!
+//! Apply the [`SGR`] control functions of a data stream to a [`TextStyle`] to find the graphic rendition of the
+//! following text. Other control functions are ignored.
+//!
+//! ```
+//! use ansi_control_codes::control_sequences::{GraphicRendition, SGR};
+//! use ansi_control_codes::text_style::{Intensity, TextStyle};
+//!
+//! let mut style = TextStyle::default();
+//! style.apply(&SGR(Some(vec![GraphicRendition::HighIntensity, GraphicRendition::Italicized])))?;
+//! style.apply(&SGR(Some(vec![GraphicRendition::NormalStyle])))?;
+//! assert_eq!(style.intensity, Intensity::High);
+//! assert!(!style.italic);
+//! # Ok::<(), ansi_control_codes::control_sequences::InvalidSequence>(())
+//! ```
+//!
+//! Use [`transition_to`][TextStyle::transition_to] to find the shortest [`SGR`] that changes from one style to
+//! another. Aspects that are the same in both styles are not repeated, and when it is shorter, the graphic rendition is
+//! reset to the default first.
+//!
+//! ```
+//! use ansi_control_codes::control_sequences::Color;
+//! use ansi_control_codes::text_style::{Intensity, TextStyle};
+//!
+//! let bold = TextStyle {
+//!     intensity: Intensity::High,
+//!     ..Default::default()
+//! };
+//! let bold_red = TextStyle {
+//!     foreground: Some(Color::Indexed(1)),
+//!     ..bold
+//! };
+//!
+//! assert_eq!(bold.transition_to(&bold_red).unwrap(), "\x1b[31m");
+//! assert_eq!(bold_red.transition_to(&TextStyle::default()).unwrap(), "\x1b[0m");
+//! assert_eq!(bold.transition_to(&bold), None);
+//! ```
+//!
+//! ## Colors
+//!
+//! The basic colors (for example [`RedForeground`][GraphicRendition::RedForeground]) and the bright colors (for
+//! example [`BrightRedForeground`][GraphicRendition::BrightRedForeground]) are the first 16 colors of the palette of
+//! 256 colors. They are tracked as [`Color::Indexed`], and transitions select them with their shorter parameters.
+//!
+//! ## Unsupported Aspects
+//!
+//! [`Fraktur`][GraphicRendition::Fraktur], [`Encircled`][GraphicRendition::Encircled] and the ideogram renditions are
+//! rarely supported by terminals, and are not tracked by a [`TextStyle`].
+
+use std::fmt;
+
+use crate::{
+    control_sequences::{
+        Color, Font, GraphicRendition, InvalidSequence, Sequence, UnderlineStyle, SGR,
+    },
+    ControlFunction, ControlFunctionType,
+};
+
+/// The intensity of text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Intensity {
+    /// Normal intensity (neither bold nor faint).
+    #[default]
+    Normal,
+
+    /// Bold or increased intensity.
+    High,
+
+    /// Faint, decreased intensity or second color.
+    Low,
+}
+
+/// The blinking of text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Blink {
+    /// Steady (not blinking).
+    #[default]
+    Steady,
+
+    /// Slowly blinking (less than 150 per minute).
+    Slow,
+
+    /// Rapidly blinking (more than 150 per minute).
+    Rapid,
+}
+
+/// The fonts, in the order of their graphic renditions.
+const FONTS: [(Font, GraphicRendition); 10] = [
+    (Font::Primary, GraphicRendition::PrimaryFont),
+    (Font::Alternative1, GraphicRendition::FirstAlternativeFont),
+    (Font::Alternative2, GraphicRendition::SecondAlternativeFont),
+    (Font::Alternative3, GraphicRendition::ThirdAlternativeFont),
+    (Font::Alternative4, GraphicRendition::ForthAlternativeFont),
+    (Font::Alternative5, GraphicRendition::FifthAlternativeFont),
+    (Font::Alternative6, GraphicRendition::SixthAlternativeFont),
+    (Font::Alternative7, GraphicRendition::SeventhAlternativeFont),
+    (Font::Alternative8, GraphicRendition::EighthAlternativeFont),
+    (Font::Alternative9, GraphicRendition::NinthAlternativeFont),
+];
+
+/// The basic and the bright foreground colors, indexed by their position in the palette of 256 colors.
+const FOREGROUND_COLORS: [GraphicRendition; 16] = [
+    GraphicRendition::BlackForeground,
+    GraphicRendition::RedForeground,
+    GraphicRendition::GreenForeground,
+    GraphicRendition::YellowForeground,
+    GraphicRendition::BlueForeground,
+    GraphicRendition::MagentaForeground,
+    GraphicRendition::CyanForeground,
+    GraphicRendition::WhiteForeground,
+    GraphicRendition::BrightBlackForeground,
+    GraphicRendition::BrightRedForeground,
+    GraphicRendition::BrightGreenForeground,
+    GraphicRendition::BrightYellowForeground,
+    GraphicRendition::BrightBlueForeground,
+    GraphicRendition::BrightMagentaForeground,
+    GraphicRendition::BrightCyanForeground,
+    GraphicRendition::BrightWhiteForeground,
+];
+
+/// The basic and the bright background colors, indexed by their position in the palette of 256 colors.
+const BACKGROUND_COLORS: [GraphicRendition; 16] = [
+    GraphicRendition::BlackBackground,
+    GraphicRendition::RedBackground,
+    GraphicRendition::GreenBackground,
+    GraphicRendition::YellowBackground,
+    GraphicRendition::BlueBackground,
+    GraphicRendition::MagentaBackground,
+    GraphicRendition::CyanBackground,
+    GraphicRendition::WhiteBackground,
+    GraphicRendition::BrightBlackBackground,
+    GraphicRendition::BrightRedBackground,
+    GraphicRendition::BrightGreenBackground,
+    GraphicRendition::BrightYellowBackground,
+    GraphicRendition::BrightBlueBackground,
+    GraphicRendition::BrightMagentaBackground,
+    GraphicRendition::BrightCyanBackground,
+    GraphicRendition::BrightWhiteBackground,
+];
+
+/// The graphic rendition of text, established by [`SGR`].
+///
+/// The default style is the default rendition ([`GraphicRendition::Default`]). Colors that are `None` are the
+/// default colors of the implementation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TextStyle {
+    /// The intensity.
+    pub intensity: Intensity,
+
+    /// Italicized.
+    pub italic: bool,
+
+    /// The style of underlines.
+    pub underline: UnderlineStyle,
+
+    /// The color of underlines.
+    pub underline_color: Option<Color>,
+
+    /// The blinking.
+    pub blink: Blink,
+
+    /// Negative image.
+    pub inverse: bool,
+
+    /// Concealed characters.
+    pub concealed: bool,
+
+    /// Crossed-out (characters still legible but marked as to be deleted).
+    pub crossed_out: bool,
+
+    /// The font, selected by the graphic renditions `10` to `19`.
+    pub font: Font,
+
+    /// The foreground color.
+    pub foreground: Option<Color>,
+
+    /// The background color.
+    pub background: Option<Color>,
+
+    /// Overlined.
+    pub overline: bool,
+
+    /// Framed.
+    pub framed: bool,
+}
+
+impl TextStyle {
+    /// Applies the graphic renditions of `control_function` to this style, if it is a SELECT GRAPHIC RENDITION
+    /// ([`SGR`]). Other control functions are ignored.
+    ///
+    /// If the parameters of the [`SGR`] cannot be decoded, the error is returned and this style is left unchanged.
+    pub fn apply(&mut self, control_function: &ControlFunction) -> Result<(), InvalidSequence> {
+        if control_function.kind() != ControlFunctionType::ControlSequence
+            || control_function.final_byte() != b'm'
+            || !control_function.intermediates().is_empty()
+            || control_function.private_parameter().is_some()
+        {
+            return Ok(());
+        }
+
+        if let Sequence::SelectGraphicRendition(graphic_renditions) =
+            Sequence::try_from(control_function)?
+        {
+            for graphic_rendition in graphic_renditions {
+                self.apply_graphic_rendition(graphic_rendition);
+            }
+        }
+        Ok(())
+    }
+
+    /// Applies a single graphic rendition to this style.
+    pub fn apply_graphic_rendition(&mut self, graphic_rendition: GraphicRendition) {
+        match graphic_rendition {
+            GraphicRendition::Default => *self = TextStyle::default(),
+            GraphicRendition::HighIntensity => self.intensity = Intensity::High,
+            GraphicRendition::LowIntensity => self.intensity = Intensity::Low,
+            GraphicRendition::NormalIntensity => self.intensity = Intensity::Normal,
+            GraphicRendition::Italicized => self.italic = true,
+            GraphicRendition::NormalStyle => self.italic = false,
+            GraphicRendition::Underlined => self.underline = UnderlineStyle::Single,
+            GraphicRendition::DoublyUnderlined => self.underline = UnderlineStyle::Double,
+            GraphicRendition::UnderlineStyle(style) => self.underline = style,
+            GraphicRendition::NotUnderlined => self.underline = UnderlineStyle::None,
+            GraphicRendition::UnderlineColor(color) => self.underline_color = Some(color),
+            GraphicRendition::DefaultUnderlineColor => self.underline_color = None,
+            GraphicRendition::SlowlyBlinking => self.blink = Blink::Slow,
+            GraphicRendition::RapidlyBlinking => self.blink = Blink::Rapid,
+            GraphicRendition::NotBlinking => self.blink = Blink::Steady,
+            GraphicRendition::Negative => self.inverse = true,
+            GraphicRendition::Positive => self.inverse = false,
+            GraphicRendition::Concealed => self.concealed = true,
+            GraphicRendition::Revealed => self.concealed = false,
+            GraphicRendition::CrossedOut => self.crossed_out = true,
+            GraphicRendition::NotCrossedOut => self.crossed_out = false,
+            GraphicRendition::Foreground(color) => self.foreground = Some(color),
+            GraphicRendition::DefaultForeground => self.foreground = None,
+            GraphicRendition::Background(color) => self.background = Some(color),
+            GraphicRendition::DefaultBackground => self.background = None,
+            GraphicRendition::Overlined => self.overline = true,
+            GraphicRendition::NotOverlined => self.overline = false,
+            GraphicRendition::Framed => self.framed = true,
+            GraphicRendition::NotFramed => self.framed = false,
+            _ => {
+                if let Some((font, _)) = FONTS
+                    .iter()
+                    .find(|(_, rendition)| *rendition == graphic_rendition)
+                {
+                    self.font = *font;
+                } else if let Some(index) = FOREGROUND_COLORS
+                    .iter()
+                    .position(|rendition| *rendition == graphic_rendition)
+                {
+                    self.foreground = Some(Color::Indexed(index as u8));
+                } else if let Some(index) = BACKGROUND_COLORS
+                    .iter()
+                    .position(|rendition| *rendition == graphic_rendition)
+                {
+                    self.background = Some(Color::Indexed(index as u8));
+                }
+            }
+        }
+    }
+
+    /// Returns the shortest SELECT GRAPHIC RENDITION ([`SGR`]) that changes from this style to `other`, or `None` if
+    /// both styles are the same.
+    ///
+    /// Only the aspects that differ are changed, unless resetting to the default rendition first is shorter.
+    pub fn transition_to(&self, other: &TextStyle) -> Option<ControlFunction<'static>> {
+        if self == other {
+            return None;
+        }
+
+        let changes = SGR(Some(self.changes_to(other)));
+        let mut graphic_renditions = vec![GraphicRendition::Default];
+        graphic_renditions.extend(TextStyle::default().changes_to(other));
+        let reset = SGR(Some(graphic_renditions));
+
+        if encoded_length(&reset) < encoded_length(&changes) {
+            Some(reset)
+        } else {
+            Some(changes)
+        }
+    }
+
+    /// Returns the graphic renditions that change all aspects of this style that differ from `other`.
+    fn changes_to(&self, other: &TextStyle) -> Vec<GraphicRendition> {
+        let mut graphic_renditions = Vec::new();
+
+        if self.intensity != other.intensity {
+            // many terminals track bold and faint separately, leave the current intensity first.
+            if self.intensity != Intensity::Normal {
+                graphic_renditions.push(GraphicRendition::NormalIntensity);
+            }
+            match other.intensity {
+                Intensity::Normal => {}
+                Intensity::High => graphic_renditions.push(GraphicRendition::HighIntensity),
+                Intensity::Low => graphic_renditions.push(GraphicRendition::LowIntensity),
+            }
+        }
+        if self.italic != other.italic {
+            graphic_renditions.push(if other.italic {
+                GraphicRendition::Italicized
+            } else {
+                GraphicRendition::NormalStyle
+            });
+        }
+        if self.underline != other.underline {
+            graphic_renditions.push(match other.underline {
+                UnderlineStyle::None => GraphicRendition::NotUnderlined,
+                UnderlineStyle::Single => GraphicRendition::Underlined,
+                UnderlineStyle::Double => GraphicRendition::DoublyUnderlined,
+                style => GraphicRendition::UnderlineStyle(style),
+            });
+        }
+        if self.blink != other.blink {
+            graphic_renditions.push(match other.blink {
+                Blink::Steady => GraphicRendition::NotBlinking,
+                Blink::Slow => GraphicRendition::SlowlyBlinking,
+                Blink::Rapid => GraphicRendition::RapidlyBlinking,
+            });
+        }
+        if self.inverse != other.inverse {
+            graphic_renditions.push(if other.inverse {
+                GraphicRendition::Negative
+            } else {
+                GraphicRendition::Positive
+            });
+        }
+        if self.concealed != other.concealed {
+            graphic_renditions.push(if other.concealed {
+                GraphicRendition::Concealed
+            } else {
+                GraphicRendition::Revealed
+            });
+        }
+        if self.crossed_out != other.crossed_out {
+            graphic_renditions.push(if other.crossed_out {
+                GraphicRendition::CrossedOut
+            } else {
+                GraphicRendition::NotCrossedOut
+            });
+        }
+        if self.font != other.font {
+            graphic_renditions.push(FONTS[other.font as usize].1);
+        }
+        if self.foreground != other.foreground {
+            graphic_renditions.push(match other.foreground {
+                None => GraphicRendition::DefaultForeground,
+                Some(Color::Indexed(index)) if index < 16 => FOREGROUND_COLORS[index as usize],
+                Some(color) => GraphicRendition::Foreground(color),
+            });
+        }
+        if self.background != other.background {
+            graphic_renditions.push(match other.background {
+                None => GraphicRendition::DefaultBackground,
+                Some(Color::Indexed(index)) if index < 16 => BACKGROUND_COLORS[index as usize],
+                Some(color) => GraphicRendition::Background(color),
+            });
+        }
+        if self.framed != other.framed {
+            graphic_renditions.push(if other.framed {
+                GraphicRendition::Framed
+            } else {
+                GraphicRendition::NotFramed
+            });
+        }
+        if self.overline != other.overline {
+            graphic_renditions.push(if other.overline {
+                GraphicRendition::Overlined
+            } else {
+                GraphicRendition::NotOverlined
+            });
+        }
+        if self.underline_color != other.underline_color {
+            graphic_renditions.push(match other.underline_color {
+                None => GraphicRendition::DefaultUnderlineColor,
+                Some(color) => GraphicRendition::UnderlineColor(color),
+            });
+        }
+
+        graphic_renditions
+    }
+}
+
+/// Counts the bytes written to it.
+struct Length(usize);
+
+impl fmt::Write for Length {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += s.len();
+        Ok(())
+    }
+}
+
+/// Returns the number of bytes of the 7-bit representation of `control_function`.
+fn encoded_length(control_function: &ControlFunction) -> usize {
+    let mut length = Length(0);
+    // writing to `Length` never fails.
+    let _ = control_function.format_to(&mut length);
+    length.0
+}
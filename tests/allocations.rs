@@ -0,0 +1,58 @@
+//! Tests that control functions are created and written without heap allocation.
+//!
+//! The allocator of this test binary counts the heap allocations of each thread. It is replaced in its own test
+//! binary, so that it does not affect any other tests.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+};
+
+use ansi_control_codes::{
+    c0::BEL,
+    control_sequences::{Color, GraphicRendition, UnderlineStyle, CUP, ED, SGR},
+};
+
+/// Allocator that counts the heap allocations of each thread.
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|allocations| allocations.set(allocations.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Test that control functions are created and written without heap allocation.
+#[test]
+fn write_without_allocation() {
+    let mut bytes = Vec::with_capacity(64);
+    let mut string = String::with_capacity(64);
+
+    let graphic_renditions = vec![
+        GraphicRendition::UnderlineStyle(UnderlineStyle::Curly),
+        GraphicRendition::Foreground(Color::Rgb(255, 128, 0)),
+    ];
+
+    let allocations = ALLOCATIONS.with(Cell::get);
+    CUP(Some(5), Some(13)).write_to(&mut bytes).unwrap();
+    ED(None).write_to(&mut bytes).unwrap();
+    BEL.write_to(&mut bytes).unwrap();
+    SGR(Some(graphic_renditions)).write_to(&mut bytes).unwrap();
+    CUP(Some(5), Some(13)).format_to(&mut string).unwrap();
+    assert_eq!(ALLOCATIONS.with(Cell::get), allocations);
+
+    assert_eq!(bytes, b"\x1b[5;13H\x1b[0J\x07\x1b[4:3;38;2;255;128;0m");
+    assert_eq!(string, "\x1b[5;13H");
+}
@@ -0,0 +1,40 @@
+//! Tests of the macros `csi!` and `sgr!`.
+//!
+//! The macros expand to paths of this crate, which can only be used from outside of the crate.
+
+use ansi_control_codes::{
+    control_sequences::{ErasePage, GraphicRendition, CUP, DTA, ED, SGR, SM},
+    csi,
+    modes::Mode,
+    sgr,
+};
+
+/// Test that the macros create the same control sequences as the functions.
+#[test]
+fn compile_time_constants() {
+    const CLEAR_SCREEN: &str = csi!(ED, 2);
+    const HOME: &[u8] = csi!(CUP).as_bytes();
+
+    assert_eq!(CLEAR_SCREEN, ED(Some(ErasePage::BeginToEnd)).to_string());
+    assert_eq!(HOME, b"\x1b[H");
+    assert_eq!(csi!(CUP, 5, 13), CUP(Some(5), Some(13)).to_string());
+    assert_eq!(csi!(DTA, 80, 24,), DTA(80, 24).to_string());
+    assert_eq!(csi!(PTX, 1), "\x1b[1\\");
+    assert_eq!(csi!(SACS, 3), "\x1b[3 \\");
+    assert_eq!(
+        csi!(SM, 4, 12),
+        SM(vec![Mode::InsertionReplacementMode, Mode::SendReceiveMode]).to_string()
+    );
+
+    assert_eq!(sgr!(), "\x1b[m");
+    assert_eq!(sgr!(Default), SGR(None).to_string());
+    assert_eq!(
+        sgr!(HighIntensity, RedForeground, IdeogramUnderline),
+        SGR(Some(vec![
+            GraphicRendition::HighIntensity,
+            GraphicRendition::RedForeground,
+            GraphicRendition::IdeogramUnderline
+        ]))
+        .to_string()
+    );
+}